@@ -1,5 +1,7 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use ecdsa::signature::Verifier;
+use p256::ecdsa::{Signature, VerifyingKey};
 use rmcp::{
     handler::server::{ServerHandler, tool::ToolRouter, wrapper::Parameters},
     model::{ServerCapabilities, ServerInfo},
@@ -12,8 +14,11 @@ use serde_json::Value;
 use sysinfo::System;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+use std::collections::HashMap;
 use std::fmt::Write;
-use std::sync::{Arc, LazyLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 struct IapSystemInfoRequest {}
@@ -24,6 +29,19 @@ struct DiskUsageRequest {}
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 struct ProcessListRequest {}
 
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+struct BenchmarkRequest {
+    /// CPU busy-loop and scratch-file-write iterations to attempt. Defaults
+    /// to 100,000; hard-capped at 10,000,000 regardless of what's requested.
+    #[serde(default)]
+    iterations: Option<u64>,
+    /// Wall-clock budget for the whole workload, in milliseconds. Defaults
+    /// to 2,000ms; hard-capped at 10,000ms. Whichever of `iterations` or
+    /// `duration_ms` is hit first stops the run.
+    #[serde(default)]
+    duration_ms: Option<u64>,
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 struct IapContext {
     payload: Value,
@@ -34,6 +52,9 @@ tokio::task_local! {
     static REQUEST_HEADERS: Vec<(String, String)>;
 }
 
+/// Base64url-decodes the JWT payload without checking the signature. Used only
+/// when `strict_verify: false` is set in the IAP config, e.g. for local runs
+/// without a real IAP in front.
 fn decode_iap_jwt(jwt: &str) -> Option<IapContext> {
     let parts: Vec<&str> = jwt.split('.').collect();
     if parts.len() != 3 {
@@ -46,6 +67,309 @@ fn decode_iap_jwt(jwt: &str) -> Option<IapContext> {
     Some(IapContext { payload })
 }
 
+const IAP_JWK_URL: &str = "https://www.gstatic.com/iap/verify/public_key-jwk";
+const IAP_ISSUER: &str = "https://cloud.google.com/iap";
+const IAP_CLOCK_SKEW_SECS: i64 = 30;
+
+/// On-disk YAML files layered (in order) into the live [`IapConfig`]. Later
+/// files override fields set by earlier ones; any file that's missing is
+/// skipped rather than treated as an error.
+const IAP_CONFIG_FILES: [&str; 3] = [
+    "iap_settings.yaml",
+    "iap_service_settings.yaml",
+    "iap_programmatic_settings.yaml",
+];
+
+const IAP_CONFIG_DEFAULT_JWKS_REFRESH_SECS: u64 = 3600;
+const IAP_CONFIG_DEFAULT_BIND_ADDRESS: &str = "0.0.0.0";
+const IAP_CONFIG_DEFAULT_PORT: u16 = 8080;
+const IAP_CONFIG_WATCH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Validated, typed IAP configuration. Replaces the old behavior of reading
+/// `IAP_CONFIG_FILES` as raw text and dumping it into the system-info report.
+#[derive(Debug, Clone, serde::Serialize)]
+struct IapConfig {
+    expected_audience: Option<String>,
+    allowed_issuers: Vec<String>,
+    jwks_refresh_secs: u64,
+    bind_address: String,
+    port: u16,
+    strict_verify: bool,
+}
+
+impl Default for IapConfig {
+    fn default() -> Self {
+        IapConfig {
+            expected_audience: None,
+            allowed_issuers: vec![IAP_ISSUER.to_string()],
+            jwks_refresh_secs: IAP_CONFIG_DEFAULT_JWKS_REFRESH_SECS,
+            bind_address: IAP_CONFIG_DEFAULT_BIND_ADDRESS.to_string(),
+            port: IAP_CONFIG_DEFAULT_PORT,
+            strict_verify: true,
+        }
+    }
+}
+
+/// Mirrors [`IapConfig`] but with every field optional, so each YAML file only
+/// needs to specify the settings it overrides.
+#[derive(Debug, Default, serde::Deserialize)]
+struct IapConfigFile {
+    #[serde(default)]
+    expected_audience: Option<String>,
+    #[serde(default)]
+    allowed_issuers: Option<Vec<String>>,
+    #[serde(default)]
+    jwks_refresh_secs: Option<u64>,
+    #[serde(default)]
+    bind_address: Option<String>,
+    #[serde(default)]
+    port: Option<u16>,
+    #[serde(default)]
+    strict_verify: Option<bool>,
+}
+
+fn validate_iap_config(config: &IapConfig) -> Result<()> {
+    if config.allowed_issuers.is_empty() {
+        anyhow::bail!("IAP config: allowed_issuers must not be empty");
+    }
+    if config.jwks_refresh_secs == 0 {
+        anyhow::bail!("IAP config: jwks_refresh_secs must be greater than zero");
+    }
+    if config.port == 0 {
+        anyhow::bail!("IAP config: port must be nonzero");
+    }
+    Ok(())
+}
+
+/// Layers `IAP_CONFIG_FILES` over [`IapConfig::default`] and validates the
+/// result. Returns an error on the first malformed file so startup fails
+/// fast instead of serving with partially-applied config.
+fn load_iap_config() -> Result<IapConfig> {
+    let mut config = IapConfig::default();
+    for file in IAP_CONFIG_FILES {
+        let Ok(content) = std::fs::read_to_string(file) else {
+            continue;
+        };
+        let layer: IapConfigFile = serde_yaml::from_str(&content)
+            .with_context(|| format!("parsing '{}' as IAP config", file))?;
+        if let Some(v) = layer.expected_audience {
+            config.expected_audience = Some(v);
+        }
+        if let Some(v) = layer.allowed_issuers {
+            config.allowed_issuers = v;
+        }
+        if let Some(v) = layer.jwks_refresh_secs {
+            config.jwks_refresh_secs = v;
+        }
+        if let Some(v) = layer.bind_address {
+            config.bind_address = v;
+        }
+        if let Some(v) = layer.port {
+            config.port = v;
+        }
+        if let Some(v) = layer.strict_verify {
+            config.strict_verify = v;
+        }
+    }
+    validate_iap_config(&config)?;
+    Ok(config)
+}
+
+static IAP_CONFIG: LazyLock<RwLock<Arc<IapConfig>>> =
+    LazyLock::new(|| RwLock::new(Arc::new(IapConfig::default())));
+
+fn iap_config() -> Arc<IapConfig> {
+    IAP_CONFIG
+        .read()
+        .map(|c| c.clone())
+        .unwrap_or_else(|_| Arc::new(IapConfig::default()))
+}
+
+fn iap_config_files_signature() -> Vec<Option<SystemTime>> {
+    IAP_CONFIG_FILES
+        .iter()
+        .map(|f| std::fs::metadata(f).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Polls `IAP_CONFIG_FILES`' mtimes and hot-reloads `IAP_CONFIG` whenever one
+/// changes, so operators can rotate the expected audience or retune the JWKS
+/// refresh interval without restarting the Cloud Run instance. A reload that
+/// fails validation is logged and ignored, keeping the last-good config live.
+async fn watch_iap_config() {
+    let mut last_signature = iap_config_files_signature();
+    loop {
+        tokio::time::sleep(IAP_CONFIG_WATCH_INTERVAL).await;
+        let signature = iap_config_files_signature();
+        if signature == last_signature {
+            continue;
+        }
+        last_signature = signature;
+        match load_iap_config() {
+            Ok(config) => {
+                tracing::info!("Reloaded IAP configuration after file change");
+                *IAP_CONFIG.write().unwrap() = Arc::new(config);
+            }
+            Err(e) => {
+                tracing::error!("Ignoring invalid IAP configuration reload: {:?}", e);
+            }
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct IapJwk {
+    kid: String,
+    crv: String,
+    x: String,
+    y: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct IapJwkSet {
+    keys: Vec<IapJwk>,
+}
+
+struct IapJwksCache {
+    keys: HashMap<String, VerifyingKey>,
+    fetched_at: Instant,
+}
+
+static IAP_JWKS: LazyLock<RwLock<Option<IapJwksCache>>> = LazyLock::new(|| RwLock::new(None));
+
+fn iap_verify_enabled() -> bool {
+    iap_config().strict_verify
+}
+
+fn iap_expected_audience() -> Option<String> {
+    iap_config().expected_audience.clone()
+}
+
+async fn fetch_iap_jwks() -> Result<HashMap<String, VerifyingKey>> {
+    let jwk_set: IapJwkSet = reqwest::get(IAP_JWK_URL)
+        .await
+        .context("failed to fetch IAP JWKS")?
+        .json()
+        .await
+        .context("failed to parse IAP JWKS")?;
+
+    let mut keys = HashMap::new();
+    for jwk in jwk_set.keys {
+        if jwk.crv != "P-256" {
+            continue;
+        }
+        let Ok(x) = URL_SAFE_NO_PAD.decode(&jwk.x) else {
+            continue;
+        };
+        let Ok(y) = URL_SAFE_NO_PAD.decode(&jwk.y) else {
+            continue;
+        };
+        let point = p256::EncodedPoint::from_affine_coordinates(x.as_slice().into(), y.as_slice().into(), false);
+        if let Ok(key) = VerifyingKey::from_encoded_point(&point) {
+            keys.insert(jwk.kid, key);
+        }
+    }
+    Ok(keys)
+}
+
+/// Looks up the verifying key for `kid`, refreshing the cached JWK set when it's
+/// stale or the key isn't found (handles Google's periodic key rotation).
+async fn iap_verifying_key(kid: &str) -> Option<VerifyingKey> {
+    let refresh_interval = Duration::from_secs(iap_config().jwks_refresh_secs);
+    if let Ok(cache) = IAP_JWKS.read() {
+        if let Some(entry) = cache.as_ref() {
+            if entry.fetched_at.elapsed() < refresh_interval {
+                if let Some(key) = entry.keys.get(kid) {
+                    return Some(*key);
+                }
+            }
+        }
+    }
+
+    match fetch_iap_jwks().await {
+        Ok(keys) => {
+            let found = keys.get(kid).copied();
+            if let Ok(mut cache) = IAP_JWKS.write() {
+                *cache = Some(IapJwksCache {
+                    keys,
+                    fetched_at: Instant::now(),
+                });
+            }
+            found
+        }
+        Err(e) => {
+            tracing::error!("Failed to refresh IAP JWKS: {:?}", e);
+            None
+        }
+    }
+}
+
+/// Verifies the ES256 signature and claims of an `x-goog-iap-jwt-assertion`
+/// header, returning the decoded claims only when the token is authentic.
+async fn verify_iap_jwt(jwt: &str) -> Option<IapContext> {
+    let parts: Vec<&str> = jwt.split('.').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let (header_b64, payload_b64, sig_b64) = (parts[0], parts[1], parts[2]);
+
+    let header: Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(header_b64).ok()?).ok()?;
+    if header.get("alg").and_then(Value::as_str) != Some("ES256") {
+        tracing::warn!("IAP JWT uses unsupported alg: {:?}", header.get("alg"));
+        return None;
+    }
+    let kid = header.get("kid").and_then(Value::as_str)?;
+
+    let verifying_key = iap_verifying_key(kid).await?;
+    let signature = Signature::from_slice(&URL_SAFE_NO_PAD.decode(sig_b64).ok()?).ok()?;
+    let signed_input = format!("{}.{}", header_b64, payload_b64);
+    if verifying_key.verify(signed_input.as_bytes(), &signature).is_err() {
+        tracing::warn!("IAP JWT signature verification failed");
+        return None;
+    }
+
+    let payload: Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload_b64).ok()?).ok()?;
+
+    let allowed_issuers = iap_config().allowed_issuers.clone();
+    if !payload
+        .get("iss")
+        .and_then(Value::as_str)
+        .is_some_and(|iss| allowed_issuers.iter().any(|allowed| allowed == iss))
+    {
+        tracing::warn!("IAP JWT has unexpected issuer: {:?}", payload.get("iss"));
+        return None;
+    }
+
+    // Every Google IAP token is signed by the same JWKS regardless of which
+    // backend/project it was minted for, so with verification enabled a
+    // missing expected audience must fail closed rather than skip the check
+    // — otherwise a valid token for a *different* project would still pass.
+    match iap_expected_audience() {
+        Some(expected_aud) => {
+            if payload.get("aud").and_then(Value::as_str) != Some(expected_aud.as_str()) {
+                tracing::warn!("IAP JWT audience mismatch");
+                return None;
+            }
+        }
+        None => {
+            tracing::error!(
+                "IAP verification is enabled but no expected_audience is configured; rejecting token"
+            );
+            return None;
+        }
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs() as i64;
+    let exp = payload.get("exp").and_then(Value::as_i64)?;
+    let iat = payload.get("iat").and_then(Value::as_i64)?;
+    if now > exp + IAP_CLOCK_SKEW_SECS || now < iat - IAP_CLOCK_SKEW_SECS {
+        tracing::warn!("IAP JWT is expired or not yet valid");
+        return None;
+    }
+
+    Some(IapContext { payload })
+}
+
 static SYSTEM_INFO_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
     LazyLock::new(|| {
         let settings = schemars::generate::SchemaSettings::draft07();
@@ -79,6 +403,17 @@ static PROCESS_LIST_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Val
         Arc::new(obj.clone())
     });
 
+static BENCHMARK_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
+    LazyLock::new(|| {
+        let settings = schemars::generate::SchemaSettings::draft07();
+        let generator = settings.into_generator();
+        let schema = generator.into_root_schema_for::<BenchmarkRequest>();
+        let mut val = serde_json::to_value(schema).unwrap();
+        let obj = val.as_object_mut().unwrap();
+        obj.remove("$schema");
+        Arc::new(obj.clone())
+    });
+
 #[derive(Clone)]
 struct SysUtils {
     tool_router: ToolRouter<Self>,
@@ -139,27 +474,21 @@ async fn collect_system_info() -> String {
 
     let _ = writeln!(report, "IAP Setup Configuration");
     let _ = writeln!(report, "-----------------------");
-    let mut found_config = false;
-    for file in &[
-        "iap_settings.yaml",
-        "iap_service_settings.yaml",
-        "iap_programmatic_settings.yaml",
-    ] {
-        if let Ok(content) = std::fs::read_to_string(file) {
-            found_config = true;
-            let _ = writeln!(report, "[{}]", file);
-            report.push_str(&content);
-            if !content.ends_with('\n') {
-                report.push('\n');
-            }
-        }
-    }
-    if !found_config {
-        let _ = writeln!(
-            report,
-            "Status:           No IAP configuration files found in current directory."
-        );
-    }
+    let config = iap_config();
+    let _ = writeln!(
+        report,
+        "Expected Audience: {}",
+        config.expected_audience.as_deref().unwrap_or("<not set>")
+    );
+    let _ = writeln!(
+        report,
+        "Allowed Issuers:   {}",
+        config.allowed_issuers.join(", ")
+    );
+    let _ = writeln!(report, "JWKS Refresh:      {}s", config.jwks_refresh_secs);
+    let _ = writeln!(report, "Bind Address:      {}", config.bind_address);
+    let _ = writeln!(report, "Port:              {}", config.port);
+    let _ = writeln!(report, "Strict Verify:     {}", config.strict_verify);
     report.push('\n');
 
     // System name and kernel
@@ -261,6 +590,156 @@ fn collect_disk_usage() -> String {
     report
 }
 
+const BENCHMARK_DEFAULT_ITERATIONS: u64 = 100_000;
+const BENCHMARK_MAX_ITERATIONS: u64 = 10_000_000;
+const BENCHMARK_DEFAULT_DURATION_MS: u64 = 2_000;
+const BENCHMARK_MAX_DURATION_MS: u64 = 10_000;
+
+#[derive(Debug, serde::Serialize)]
+struct WorkloadResult {
+    iterations_completed: u64,
+    elapsed_ms: f64,
+    ops_per_sec: f64,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct FileIoResult {
+    bytes_written: u64,
+    elapsed_ms: f64,
+    mb_per_sec: f64,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct BenchmarkReport {
+    requested_iterations: u64,
+    duration_budget_ms: u64,
+    cpu: WorkloadResult,
+    file_io: FileIoResult,
+    environment: SystemSnapshot,
+}
+
+/// Removes its scratch file on drop, so an early `?` return from the file
+/// I/O workload still leaves no trace on disk.
+struct ScratchFile(std::path::PathBuf);
+
+impl Drop for ScratchFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// Busies a core with cheap integer churn until either `max_iterations` or
+/// `deadline` is reached, whichever comes first.
+fn run_cpu_workload(max_iterations: u64, deadline: Instant) -> WorkloadResult {
+    let start = Instant::now();
+    let mut acc: u64 = 0;
+    let mut completed = 0u64;
+
+    for i in 0..max_iterations {
+        acc = acc.wrapping_add(i.wrapping_mul(2_654_435_761));
+        completed = i + 1;
+        // Checking the clock every iteration would dominate the loop itself.
+        if completed % 4096 == 0 && Instant::now() >= deadline {
+            break;
+        }
+    }
+    std::hint::black_box(acc);
+
+    let elapsed = start.elapsed();
+    let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
+    let ops_per_sec = if elapsed.as_secs_f64() > 0.0 {
+        completed as f64 / elapsed.as_secs_f64()
+    } else {
+        0.0
+    };
+
+    WorkloadResult {
+        iterations_completed: completed,
+        elapsed_ms,
+        ops_per_sec,
+    }
+}
+
+/// Disambiguates scratch file names for concurrent `benchmark` calls within
+/// the same process, since they'd otherwise all share a PID.
+static BENCHMARK_SCRATCH_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Writes sequential 64KB chunks to a scratch file in the OS temp dir until
+/// either `max_chunks` or `deadline` is reached, then removes the file.
+fn run_file_io_workload(max_chunks: u64, deadline: Instant) -> Result<FileIoResult> {
+    use std::io::Write as _;
+
+    const CHUNK: [u8; 65536] = [0xAB; 65536];
+
+    let call_id = BENCHMARK_SCRATCH_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let path = std::env::temp_dir().join(format!(
+        "sysutils-bench-{}-{}.tmp",
+        std::process::id(),
+        call_id
+    ));
+    let _scratch = ScratchFile(path.clone());
+    let mut file =
+        std::fs::File::create(&path).context("Failed to create benchmark scratch file")?;
+
+    let start = Instant::now();
+    let mut bytes_written = 0u64;
+    let mut chunks_written = 0u64;
+
+    for _ in 0..max_chunks {
+        file.write_all(&CHUNK)
+            .context("Failed to write to benchmark scratch file")?;
+        bytes_written += CHUNK.len() as u64;
+        chunks_written += 1;
+        if chunks_written % 16 == 0 && Instant::now() >= deadline {
+            break;
+        }
+    }
+    let _ = file.sync_data();
+
+    let elapsed = start.elapsed();
+    let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
+    let mb_per_sec = if elapsed.as_secs_f64() > 0.0 {
+        (bytes_written as f64 / (1024.0 * 1024.0)) / elapsed.as_secs_f64()
+    } else {
+        0.0
+    };
+
+    // `_scratch` drops here, deleting the file whether or not the writes
+    // above ran to completion.
+    Ok(FileIoResult {
+        bytes_written,
+        elapsed_ms,
+        mb_per_sec,
+    })
+}
+
+/// Runs the bounded CPU and file-I/O workloads back to back and pairs the
+/// throughput numbers with an environment snapshot, so a result is
+/// meaningful on its own when compared across machines.
+fn run_benchmark(req: &BenchmarkRequest) -> Result<BenchmarkReport> {
+    let requested_iterations = req
+        .iterations
+        .unwrap_or(BENCHMARK_DEFAULT_ITERATIONS)
+        .clamp(1, BENCHMARK_MAX_ITERATIONS);
+    let duration_budget_ms = req
+        .duration_ms
+        .unwrap_or(BENCHMARK_DEFAULT_DURATION_MS)
+        .clamp(1, BENCHMARK_MAX_DURATION_MS);
+
+    let deadline = Instant::now() + Duration::from_millis(duration_budget_ms);
+
+    let cpu = run_cpu_workload(requested_iterations, deadline);
+    let file_io = run_file_io_workload(requested_iterations, deadline)?;
+
+    Ok(BenchmarkReport {
+        requested_iterations,
+        duration_budget_ms,
+        cpu,
+        file_io,
+        environment: sample_system_snapshot(),
+    })
+}
+
 #[tool_router]
 impl SysUtils {
     fn new() -> Self {
@@ -316,6 +795,18 @@ impl SysUtils {
 
         report
     }
+
+    #[tool(
+        description = "Run a short, bounded CPU and scratch-file-write micro-benchmark and return throughput (ops/s, MB/s) paired with an environment snapshot.",
+        input_schema = "BENCHMARK_SCHEMA.clone()"
+    )]
+    async fn benchmark(&self, Parameters(req): Parameters<BenchmarkRequest>) -> String {
+        match run_benchmark(&req) {
+            Ok(report) => serde_json::to_string_pretty(&report)
+                .unwrap_or_else(|e| format!("Error serializing benchmark report: {:?}", e)),
+            Err(e) => format!("Error running benchmark: {:?}", e),
+        }
+    }
 }
 
 #[tool_handler]
@@ -331,15 +822,163 @@ impl ServerHandler for SysUtils {
     }
 }
 
+/// Server-internal counters updated from `iap_middleware`, served by `/stats`
+/// and `/metrics` so operators can monitor the deployment without going
+/// through the MCP tool path.
+struct ServerMetrics {
+    start_time: Instant,
+    total_requests: AtomicU64,
+    auth_failures: AtomicU64,
+}
+
+impl ServerMetrics {
+    fn new() -> Self {
+        Self {
+            start_time: Instant::now(),
+            total_requests: AtomicU64::new(0),
+            auth_failures: AtomicU64::new(0),
+        }
+    }
+
+    fn uptime_secs(&self) -> u64 {
+        self.start_time.elapsed().as_secs()
+    }
+}
+
+static SERVER_METRICS: LazyLock<ServerMetrics> = LazyLock::new(ServerMetrics::new);
+
+#[derive(Clone, serde::Serialize)]
+struct SystemSnapshot {
+    host_name: String,
+    kernel_version: String,
+    os_version: String,
+    cpu_cores: usize,
+    total_memory_mb: u64,
+    used_memory_mb: u64,
+    total_swap_mb: u64,
+    used_swap_mb: u64,
+}
+
+fn sample_system_snapshot() -> SystemSnapshot {
+    let mut sys = System::new_all();
+    sys.refresh_all();
+
+    SystemSnapshot {
+        host_name: System::host_name().unwrap_or_else(|| "<unknown>".to_string()),
+        kernel_version: System::kernel_version().unwrap_or_else(|| "<unknown>".to_string()),
+        os_version: System::os_version().unwrap_or_else(|| "<unknown>".to_string()),
+        cpu_cores: sys.cpus().len(),
+        total_memory_mb: sys.total_memory() / 1024 / 1024,
+        used_memory_mb: sys.used_memory() / 1024 / 1024,
+        total_swap_mb: sys.total_swap() / 1024 / 1024,
+        used_swap_mb: sys.used_swap() / 1024 / 1024,
+    }
+}
+
+struct SystemSnapshotCache {
+    snapshot: SystemSnapshot,
+    refreshed_at: Instant,
+}
+
+static SYSTEM_SNAPSHOT: LazyLock<RwLock<Option<SystemSnapshotCache>>> =
+    LazyLock::new(|| RwLock::new(None));
+const SYSTEM_SNAPSHOT_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Returns the last system sample, refreshing it at most once per
+/// `SYSTEM_SNAPSHOT_REFRESH_INTERVAL` so scraping `/stats`/`/metrics`
+/// frequently stays cheap.
+fn current_system_snapshot() -> SystemSnapshot {
+    if let Ok(cache) = SYSTEM_SNAPSHOT.read() {
+        if let Some(entry) = cache.as_ref() {
+            if entry.refreshed_at.elapsed() < SYSTEM_SNAPSHOT_REFRESH_INTERVAL {
+                return entry.snapshot.clone();
+            }
+        }
+    }
+
+    let snapshot = sample_system_snapshot();
+    if let Ok(mut cache) = SYSTEM_SNAPSHOT.write() {
+        *cache = Some(SystemSnapshotCache {
+            snapshot: snapshot.clone(),
+            refreshed_at: Instant::now(),
+        });
+    }
+    snapshot
+}
+
+#[derive(serde::Serialize)]
+struct StatsResponse {
+    #[serde(flatten)]
+    system: SystemSnapshot,
+    uptime_seconds: u64,
+    total_requests: u64,
+    auth_failures: u64,
+}
+
+async fn stats_handler() -> axum::Json<StatsResponse> {
+    axum::Json(StatsResponse {
+        system: current_system_snapshot(),
+        uptime_seconds: SERVER_METRICS.uptime_secs(),
+        total_requests: SERVER_METRICS.total_requests.load(Ordering::Relaxed),
+        auth_failures: SERVER_METRICS.auth_failures.load(Ordering::Relaxed),
+    })
+}
+
+async fn metrics_handler() -> String {
+    let system = current_system_snapshot();
+    let mut out = String::new();
+
+    let _ = writeln!(out, "# HELP bearer_rust_uptime_seconds Seconds since the process started.");
+    let _ = writeln!(out, "# TYPE bearer_rust_uptime_seconds counter");
+    let _ = writeln!(out, "bearer_rust_uptime_seconds {}", SERVER_METRICS.uptime_secs());
+
+    let _ = writeln!(out, "# HELP bearer_rust_requests_total Total HTTP requests seen by iap_middleware.");
+    let _ = writeln!(out, "# TYPE bearer_rust_requests_total counter");
+    let _ = writeln!(
+        out,
+        "bearer_rust_requests_total {}",
+        SERVER_METRICS.total_requests.load(Ordering::Relaxed)
+    );
+
+    let _ = writeln!(out, "# HELP bearer_rust_auth_failures_total Total rejected IAP JWT verifications.");
+    let _ = writeln!(out, "# TYPE bearer_rust_auth_failures_total counter");
+    let _ = writeln!(
+        out,
+        "bearer_rust_auth_failures_total {}",
+        SERVER_METRICS.auth_failures.load(Ordering::Relaxed)
+    );
+
+    let _ = writeln!(out, "# HELP bearer_rust_cpu_cores Number of CPU cores.");
+    let _ = writeln!(out, "# TYPE bearer_rust_cpu_cores gauge");
+    let _ = writeln!(out, "bearer_rust_cpu_cores {}", system.cpu_cores);
+
+    let _ = writeln!(out, "# HELP bearer_rust_memory_used_mb Used memory in MB.");
+    let _ = writeln!(out, "# TYPE bearer_rust_memory_used_mb gauge");
+    let _ = writeln!(out, "bearer_rust_memory_used_mb {}", system.used_memory_mb);
+
+    let _ = writeln!(out, "# HELP bearer_rust_memory_total_mb Total memory in MB.");
+    let _ = writeln!(out, "# TYPE bearer_rust_memory_total_mb gauge");
+    let _ = writeln!(out, "bearer_rust_memory_total_mb {}", system.total_memory_mb);
+
+    out
+}
+
 async fn iap_middleware(
     request: axum::extract::Request,
     next: axum::middleware::Next,
 ) -> axum::response::Response {
-    // Skip health endpoint
-    if request.uri().path() == "/health" {
+    use axum::response::IntoResponse;
+
+    let path = request.uri().path().to_string();
+
+    // Skip health/observability endpoints: no IAP/auth required, and we don't
+    // want a scraper hammering /metrics to inflate its own request count.
+    if path == "/health" || path == "/stats" || path == "/metrics" {
         return next.run(request).await;
     }
 
+    SERVER_METRICS.total_requests.fetch_add(1, Ordering::Relaxed);
+
     let mut headers = Vec::new();
     for (name, value) in request.headers() {
         headers.push((
@@ -359,15 +998,29 @@ async fn iap_middleware(
 
     if let Some(header_value) = iap_header {
         tracing::debug!("Found x-goog-iap-jwt-assertion header");
-        if let Ok(jwt_str) = header_value.to_str() {
-            if let Some(ctx) = decode_iap_jwt(jwt_str) {
-                tracing::info!("IAP JWT decoded successfully. Claims: {}", ctx.payload);
-                iap_context = Some(ctx);
-            } else {
-                tracing::error!("Failed to decode x-goog-iap-jwt-assertion payload");
+        let Ok(jwt_str) = header_value.to_str() else {
+            tracing::error!("x-goog-iap-jwt-assertion header contains non-UTF8 data");
+            SERVER_METRICS.auth_failures.fetch_add(1, Ordering::Relaxed);
+            return axum::http::StatusCode::UNAUTHORIZED.into_response();
+        };
+
+        if iap_verify_enabled() {
+            match verify_iap_jwt(jwt_str).await {
+                Some(ctx) => {
+                    tracing::info!("IAP JWT verified successfully. Claims: {}", ctx.payload);
+                    iap_context = Some(ctx);
+                }
+                None => {
+                    tracing::warn!("IAP JWT failed signature/claim verification");
+                    SERVER_METRICS.auth_failures.fetch_add(1, Ordering::Relaxed);
+                    return axum::http::StatusCode::UNAUTHORIZED.into_response();
+                }
             }
+        } else if let Some(ctx) = decode_iap_jwt(jwt_str) {
+            tracing::info!("IAP JWT decoded (verification disabled). Claims: {}", ctx.payload);
+            iap_context = Some(ctx);
         } else {
-            tracing::error!("x-goog-iap-jwt-assertion header contains non-UTF8 data");
+            tracing::error!("Failed to decode x-goog-iap-jwt-assertion payload");
         }
     } else {
         tracing::debug!("No x-goog-iap-jwt-assertion header found");
@@ -378,12 +1031,279 @@ async fn iap_middleware(
         .await
 }
 
+#[derive(serde::Serialize)]
+struct ToolLatencyStats {
+    tool: String,
+    iterations: usize,
+    min_ms: f64,
+    median_ms: f64,
+    p95_ms: f64,
+    max_ms: f64,
+    throughput_per_sec: f64,
+}
+
+#[derive(serde::Serialize)]
+struct BenchReport {
+    crate_version: String,
+    git_commit: String,
+    environment: SystemSnapshot,
+    tools: Vec<ToolLatencyStats>,
+}
+
+/// Resolves the short git commit hash for the report header. Falls back to
+/// "unknown" when `git` isn't on `PATH` or this isn't a checkout (e.g. a
+/// container image built from a source tarball).
+fn git_commit_hash() -> String {
+    std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn latency_stats(tool: &str, mut samples_ms: Vec<f64>, wall_elapsed_ms: f64) -> ToolLatencyStats {
+    samples_ms.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = samples_ms.len();
+    let min_ms = samples_ms.first().copied().unwrap_or(0.0);
+    let max_ms = samples_ms.last().copied().unwrap_or(0.0);
+    let median_ms = samples_ms.get(n / 2).copied().unwrap_or(0.0);
+    let p95_idx = ((n as f64) * 0.95).ceil() as usize;
+    let p95_ms = samples_ms
+        .get(p95_idx.saturating_sub(1).min(n.saturating_sub(1)))
+        .copied()
+        .unwrap_or(0.0);
+    let throughput_per_sec = if wall_elapsed_ms > 0.0 {
+        (n as f64) / (wall_elapsed_ms / 1000.0)
+    } else {
+        0.0
+    };
+
+    ToolLatencyStats {
+        tool: tool.to_string(),
+        iterations: n,
+        min_ms,
+        median_ms,
+        p95_ms,
+        max_ms,
+        throughput_per_sec,
+    }
+}
+
+/// Tool call names accepted in `bench --workload` files (the same three tools
+/// the iterations mode always runs). Keep in sync with the tool methods on
+/// [`SysUtils`].
+fn is_known_workload_tool(tool: &str) -> bool {
+    matches!(tool, "sysutils_bearer_rust" | "disk_usage" | "list_processes")
+}
+
+async fn call_tool_once(sysutils: &SysUtils, tool: &str) -> Result<()> {
+    match tool {
+        "sysutils_bearer_rust" => {
+            let _ = sysutils
+                .sysutils_bearer_rust(Parameters(IapSystemInfoRequest {}))
+                .await;
+        }
+        "disk_usage" => {
+            let _ = sysutils.disk_usage(Parameters(DiskUsageRequest {})).await;
+        }
+        "list_processes" => {
+            let _ = sysutils
+                .list_processes(Parameters(ProcessListRequest {}))
+                .await;
+        }
+        other => anyhow::bail!("unknown tool '{}'", other),
+    }
+    Ok(())
+}
+
+const WORKLOAD_DEFAULT_REPEAT: usize = 20;
+const WORKLOAD_MAX_REPEAT: usize = 100_000;
+const WORKLOAD_MAX_CONCURRENCY: usize = 64;
+
+/// One entry in a `bench --workload` JSON file: a tool to call, how many
+/// times to call it, and how many of those calls may be in flight at once.
+#[derive(Debug, serde::Deserialize)]
+struct WorkloadEntry {
+    tool: String,
+    #[serde(default)]
+    repeat: Option<usize>,
+    #[serde(default)]
+    concurrency: Option<usize>,
+}
+
+/// Runs one workload entry's repeated calls in batches of `concurrency`,
+/// timing each call individually but measuring throughput off the whole
+/// batch's wall-clock time so concurrent runs are credited for overlap.
+async fn run_workload_entry(sysutils: &SysUtils, entry: &WorkloadEntry) -> Result<ToolLatencyStats> {
+    if !is_known_workload_tool(&entry.tool) {
+        anyhow::bail!("unknown tool '{}' in workload file", entry.tool);
+    }
+    let repeat = entry
+        .repeat
+        .unwrap_or(WORKLOAD_DEFAULT_REPEAT)
+        .clamp(1, WORKLOAD_MAX_REPEAT);
+    let concurrency = entry
+        .concurrency
+        .unwrap_or(1)
+        .clamp(1, WORKLOAD_MAX_CONCURRENCY)
+        .min(repeat);
+
+    let mut samples_ms = Vec::with_capacity(repeat);
+    let batch_start = Instant::now();
+    let mut remaining = repeat;
+    while remaining > 0 {
+        let batch_size = remaining.min(concurrency);
+        let mut handles = Vec::with_capacity(batch_size);
+        for _ in 0..batch_size {
+            let sysutils = sysutils.clone();
+            let tool = entry.tool.clone();
+            handles.push(tokio::spawn(async move {
+                let start = Instant::now();
+                let _ = call_tool_once(&sysutils, &tool).await;
+                start.elapsed().as_secs_f64() * 1000.0
+            }));
+        }
+        for handle in handles {
+            samples_ms.push(handle.await.unwrap_or(0.0));
+        }
+        remaining -= batch_size;
+    }
+    let wall_elapsed_ms = batch_start.elapsed().as_secs_f64() * 1000.0;
+
+    Ok(latency_stats(&entry.tool, samples_ms, wall_elapsed_ms))
+}
+
+/// Replays a workload file's entries in order and bundles the resulting
+/// per-tool stats with an environment snapshot, mirroring [`run_bench`]'s
+/// report shape so both modes can be rendered and collected the same way.
+async fn run_workload(entries: &[WorkloadEntry]) -> Result<BenchReport> {
+    let sysutils = SysUtils::new();
+    let mut tools = Vec::with_capacity(entries.len());
+    for entry in entries {
+        tools.push(run_workload_entry(&sysutils, entry).await?);
+    }
+
+    Ok(BenchReport {
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        git_commit: git_commit_hash(),
+        environment: sample_system_snapshot(),
+        tools,
+    })
+}
+
+/// POSTs a bench report to an external results collector so runs can be
+/// tracked over time, when `BENCH_RESULTS_URL` is set. Failures are
+/// returned to the caller to log rather than ignored, but never block the
+/// local report from being printed.
+async fn post_bench_report(url: &str, report: &BenchReport) -> Result<()> {
+    let response = reqwest::Client::new()
+        .post(url)
+        .json(report)
+        .send()
+        .await
+        .with_context(|| format!("POSTing bench report to '{}'", url))?;
+    if !response.status().is_success() {
+        anyhow::bail!("results collector responded with status {}", response.status());
+    }
+    Ok(())
+}
+
+/// Invokes each registered tool `iterations` times, timing every call, and
+/// pairs the resulting latency stats with an environment snapshot so a
+/// result file is self-describing across machines and releases.
+async fn run_bench(iterations: usize) -> BenchReport {
+    let sysutils = SysUtils::new();
+
+    let mut info_samples = Vec::with_capacity(iterations);
+    let info_start = Instant::now();
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let _ = sysutils
+            .sysutils_bearer_rust(Parameters(IapSystemInfoRequest {}))
+            .await;
+        info_samples.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+    let info_elapsed_ms = info_start.elapsed().as_secs_f64() * 1000.0;
+
+    let mut disk_samples = Vec::with_capacity(iterations);
+    let disk_start = Instant::now();
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let _ = sysutils.disk_usage(Parameters(DiskUsageRequest {})).await;
+        disk_samples.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+    let disk_elapsed_ms = disk_start.elapsed().as_secs_f64() * 1000.0;
+
+    let mut processes_samples = Vec::with_capacity(iterations);
+    let processes_start = Instant::now();
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let _ = sysutils
+            .list_processes(Parameters(ProcessListRequest {}))
+            .await;
+        processes_samples.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+    let processes_elapsed_ms = processes_start.elapsed().as_secs_f64() * 1000.0;
+
+    BenchReport {
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        git_commit: git_commit_hash(),
+        environment: sample_system_snapshot(),
+        tools: vec![
+            latency_stats("sysutils_bearer_rust", info_samples, info_elapsed_ms),
+            latency_stats("disk_usage", disk_samples, disk_elapsed_ms),
+            latency_stats("list_processes", processes_samples, processes_elapsed_ms),
+        ],
+    }
+}
+
+fn render_bench_report_text(report: &BenchReport) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "Benchmark Report");
+    let _ = writeln!(out, "================\n");
+    let _ = writeln!(out, "Crate Version: {}", report.crate_version);
+    let _ = writeln!(out, "Git Commit:    {}", report.git_commit);
+    let _ = writeln!(out, "Host:          {}", report.environment.host_name);
+    let _ = writeln!(out, "Kernel:        {}", report.environment.kernel_version);
+    let _ = writeln!(out, "OS:            {}", report.environment.os_version);
+    let _ = writeln!(out, "CPU Cores:     {}", report.environment.cpu_cores);
+    let _ = writeln!(out, "Memory:        {} MB\n", report.environment.total_memory_mb);
+    let _ = writeln!(
+        out,
+        "{:<25} {:>6} {:>10} {:>10} {:>10} {:>10} {:>12}",
+        "Tool", "N", "Min(ms)", "Median(ms)", "P95(ms)", "Max(ms)", "Thpt(/s)"
+    );
+    for t in &report.tools {
+        let _ = writeln!(
+            out,
+            "{:<25} {:>6} {:>10.3} {:>10.3} {:>10.3} {:>10.3} {:>12.2}",
+            t.tool, t.iterations, t.min_ms, t.median_ms, t.p95_ms, t.max_ms, t.throughput_per_sec
+        );
+    }
+    out
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
-    // 1. Determine port and bind immediately to satisfy Cloud Run health check
-    let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
-    let addr = format!("0.0.0.0:{}", port);
-    
+    // 1. Load typed IAP configuration from the layered YAML files before
+    //    anything else. A malformed config fails startup outright (rather
+    //    than falling back to defaults) since it gates authentication —
+    //    serving on a partially-applied config would silently change who is
+    //    let in (e.g. a typo'd `expected_audience` reverting to unset, which
+    //    makes strict verification reject every IAP token).
+    let startup_config = load_iap_config().context("invalid IAP configuration at startup")?;
+    *IAP_CONFIG.write().unwrap() = Arc::new(startup_config);
+
+    // 2. Determine port and bind immediately to satisfy Cloud Run health
+    //    check. `PORT` (set by Cloud Run) overrides the configured port so
+    //    the platform's contract always wins; bind address comes from the
+    //    loaded IAP config.
+    let iap_cfg = iap_config();
+    let port = std::env::var("PORT").unwrap_or_else(|_| iap_cfg.port.to_string());
+    let addr = format!("{}:{}", iap_cfg.bind_address, port);
+
     println!("DEBUG: Starting bearer-rust version 0.3.0-debug");
     println!("DEBUG: Environment: PORT={}", port);
 
@@ -394,7 +1314,7 @@ async fn main() -> Result<()> {
     let listener = tokio::net::TcpListener::bind(&addr).await?;
     println!("DEBUG: Successfully bound to {}", addr);
 
-    // 2. Initialize tracing AFTER binding
+    // 3. Initialize tracing AFTER binding
     tracing_subscriber::registry()
         .with(
             tracing_subscriber::EnvFilter::try_from_default_env()
@@ -407,7 +1327,7 @@ async fn main() -> Result<()> {
         )
         .init();
 
-    // 3. Handle CLI arguments
+    // 4. Handle CLI arguments
     let args: Vec<String> = std::env::args().collect();
     if args.iter().any(|arg| arg == "info") {
         println!("{}", collect_system_info().await);
@@ -424,9 +1344,42 @@ async fn main() -> Result<()> {
                 .await
         );
         return Ok(());
+    } else if args.iter().any(|arg| arg == "bench") {
+        let workload_path = args
+            .iter()
+            .position(|a| a == "--workload")
+            .and_then(|i| args.get(i + 1));
+        let report = if let Some(path) = workload_path {
+            let data = std::fs::read_to_string(path)
+                .with_context(|| format!("reading workload file '{}'", path))?;
+            let entries: Vec<WorkloadEntry> = serde_json::from_str(&data)
+                .with_context(|| format!("parsing workload file '{}'", path))?;
+            run_workload(&entries).await?
+        } else {
+            let iterations = args
+                .iter()
+                .position(|a| a == "--iterations")
+                .and_then(|i| args.get(i + 1))
+                .and_then(|v| v.parse::<usize>().ok())
+                .unwrap_or(20);
+            run_bench(iterations).await
+        };
+
+        if let Ok(collector_url) = std::env::var("BENCH_RESULTS_URL") {
+            if let Err(e) = post_bench_report(&collector_url, &report).await {
+                tracing::warn!("failed to POST bench report to results collector: {:?}", e);
+            }
+        }
+
+        if args.iter().any(|arg| arg == "--json") {
+            println!("{}", serde_json::to_string_pretty(&report).unwrap());
+        } else {
+            println!("{}", render_bench_report_text(&report));
+        }
+        return Ok(());
     }
 
-    // 4. Setup MCP Service
+    // 5. Setup MCP Service
     let service_factory = || Ok(SysUtils::new());
     let session_manager = LocalSessionManager::default();
     let config = StreamableHttpServerConfig::default();
@@ -434,12 +1387,16 @@ async fn main() -> Result<()> {
 
     let app = axum::Router::new()
         .route("/health", axum::routing::get(|| async { "ok" }))
+        .route("/stats", axum::routing::get(stats_handler))
+        .route("/metrics", axum::routing::get(metrics_handler))
         .fallback_service(service)
         .layer(axum::middleware::from_fn(iap_middleware));
 
+    tokio::spawn(watch_iap_config());
+
     tracing::info!("MCP Server starting on http://{}", addr);
 
-    // 5. Serve
+    // 6. Serve
     axum::serve(listener, app)
         .with_graceful_shutdown(shutdown_signal())
         .await?;
@@ -539,6 +1496,71 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_verify_iap_jwt_checks_signature() {
+        use ecdsa::signature::Signer;
+        use p256::ecdsa::SigningKey;
+
+        let signing_key = SigningKey::random(&mut rand_core::OsRng);
+        let verifying_key = *signing_key.verifying_key();
+        let kid = "test-kid";
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        let header = serde_json::json!({"alg": "ES256", "kid": kid});
+        let payload = serde_json::json!({
+            "iss": IAP_ISSUER,
+            "aud": "test-audience",
+            "email": "test@example.com",
+            "exp": now + 3600,
+            "iat": now,
+        });
+        let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_string(&header).unwrap());
+        let payload_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_string(&payload).unwrap());
+        let signed_input = format!("{}.{}", header_b64, payload_b64);
+        let signature: Signature = signing_key.sign(signed_input.as_bytes());
+        let jwt = format!(
+            "{}.{}",
+            signed_input,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        );
+
+        {
+            let mut cache = IAP_JWKS.write().unwrap();
+            *cache = Some(IapJwksCache {
+                keys: HashMap::from([(kid.to_string(), verifying_key)]),
+                fetched_at: Instant::now(),
+            });
+        }
+
+        // verify_iap_jwt fails closed without a configured expected audience.
+        *IAP_CONFIG.write().unwrap() = Arc::new(IapConfig {
+            expected_audience: Some("test-audience".to_string()),
+            ..IapConfig::default()
+        });
+
+        let ctx = verify_iap_jwt(&jwt).await.unwrap();
+        assert_eq!(
+            ctx.payload.get("email").unwrap().as_str().unwrap(),
+            "test@example.com"
+        );
+
+        // Tampering with the payload must invalidate the signature.
+        let mut tampered_payload = payload.clone();
+        tampered_payload["email"] = serde_json::json!("attacker@example.com");
+        let tampered_payload_b64 =
+            URL_SAFE_NO_PAD.encode(serde_json::to_string(&tampered_payload).unwrap());
+        let tampered_jwt = format!(
+            "{}.{}.{}",
+            header_b64,
+            tampered_payload_b64,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        );
+        assert!(verify_iap_jwt(&tampered_jwt).await.is_none());
+    }
+
     #[tokio::test]
     async fn test_collect_system_info_with_context() {
         let payload = serde_json::json!({
@@ -592,4 +1614,132 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::OK);
     }
+
+    #[tokio::test]
+    async fn test_stats_and_metrics_endpoints() {
+        use axum::{
+            body::Body,
+            http::{Request, StatusCode},
+        };
+        use tower::ServiceExt;
+
+        let service_factory = || Ok(SysUtils::new());
+        let session_manager = LocalSessionManager::default();
+        let config = StreamableHttpServerConfig::default();
+        let service = StreamableHttpService::new(service_factory, session_manager.into(), config);
+
+        let app = axum::Router::new()
+            .route("/health", axum::routing::get(|| async { "ok" }))
+            .route("/stats", axum::routing::get(stats_handler))
+            .route("/metrics", axum::routing::get(metrics_handler))
+            .fallback_service(service)
+            .layer(axum::middleware::from_fn(iap_middleware));
+
+        let stats_response = app
+            .clone()
+            .oneshot(Request::builder().uri("/stats").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(stats_response.status(), StatusCode::OK);
+
+        let metrics_response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/metrics")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(metrics_response.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn test_latency_stats() {
+        let stats = latency_stats("disk_usage", vec![5.0, 1.0, 3.0, 4.0, 2.0], 15.0);
+        assert_eq!(stats.iterations, 5);
+        assert_eq!(stats.min_ms, 1.0);
+        assert_eq!(stats.median_ms, 3.0);
+        assert_eq!(stats.max_ms, 5.0);
+        assert!(stats.throughput_per_sec > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_run_bench_covers_every_tool() {
+        let report = run_bench(2).await;
+        assert_eq!(report.tools.len(), 3);
+        assert!(report.tools.iter().all(|t| t.iterations == 2));
+        assert!(!report.crate_version.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_run_workload_covers_requested_repeats() {
+        let entries = vec![
+            WorkloadEntry {
+                tool: "sysutils_bearer_rust".to_string(),
+                repeat: Some(3),
+                concurrency: None,
+            },
+            WorkloadEntry {
+                tool: "disk_usage".to_string(),
+                repeat: Some(4),
+                concurrency: Some(2),
+            },
+        ];
+        let report = run_workload(&entries).await.unwrap();
+        assert_eq!(report.tools.len(), 2);
+        assert_eq!(report.tools[0].iterations, 3);
+        assert_eq!(report.tools[1].iterations, 4);
+        assert!(report.tools.iter().all(|t| t.throughput_per_sec > 0.0));
+    }
+
+    #[tokio::test]
+    async fn test_run_workload_rejects_unknown_tool() {
+        let entries = vec![WorkloadEntry {
+            tool: "not_a_real_tool".to_string(),
+            repeat: Some(1),
+            concurrency: None,
+        }];
+        assert!(run_workload(&entries).await.is_err());
+    }
+
+    #[test]
+    fn test_run_benchmark_reports_throughput() {
+        let report = run_benchmark(&BenchmarkRequest {
+            iterations: Some(1000),
+            duration_ms: Some(1000),
+        })
+        .unwrap();
+        assert_eq!(report.requested_iterations, 1000);
+        assert!(report.cpu.iterations_completed > 0);
+        assert!(report.file_io.bytes_written > 0);
+    }
+
+    #[test]
+    fn test_run_benchmark_clamps_oversized_request() {
+        let report = run_benchmark(&BenchmarkRequest {
+            iterations: Some(u64::MAX),
+            duration_ms: Some(u64::MAX),
+        })
+        .unwrap();
+        assert_eq!(report.requested_iterations, BENCHMARK_MAX_ITERATIONS);
+        assert_eq!(report.duration_budget_ms, BENCHMARK_MAX_DURATION_MS);
+    }
+
+    #[test]
+    fn test_run_file_io_workload_cleans_up_scratch_file() {
+        let deadline = Instant::now() + Duration::from_millis(500);
+        let result = run_file_io_workload(10, deadline).unwrap();
+        assert!(result.bytes_written > 0);
+
+        let leftover = std::fs::read_dir(std::env::temp_dir())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .any(|e| {
+                e.file_name()
+                    .to_string_lossy()
+                    .starts_with(&format!("sysutils-bench-{}", std::process::id()))
+            });
+        assert!(!leftover, "benchmark scratch file was not cleaned up");
+    }
 }
@@ -1,5 +1,9 @@
 use anyhow::{Context, Result};
 use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use ecdsa::signature::Verifier;
+use hmac::{Hmac, Mac};
+use p256::ecdsa::{Signature, VerifyingKey};
+use sha2::{Digest, Sha256};
 use rmcp::{
     handler::server::{ServerHandler, tool::ToolRouter, wrapper::Parameters},
     model::{ServerCapabilities, ServerInfo},
@@ -12,8 +16,10 @@ use serde_json::Value;
 use sysinfo::System;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-use std::sync::{Arc, LazyLock, OnceLock};
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
 use std::fmt::Write;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 // Google Cloud Dependencies
 use google_apikeys2::ApiKeysService;
@@ -48,6 +54,160 @@ fn decode_iap_jwt(jwt: &str) -> Option<IapContext> {
     Some(IapContext { payload })
 }
 
+const IAP_JWK_URL: &str = "https://www.gstatic.com/iap/verify/public_key-jwk";
+const IAP_ISSUER: &str = "https://cloud.google.com/iap";
+const IAP_CLOCK_SKEW_SECS: i64 = 30;
+const IAP_JWKS_REFRESH_INTERVAL: Duration = Duration::from_secs(3600);
+
+#[derive(Debug, serde::Deserialize)]
+struct IapJwk {
+    kid: String,
+    crv: String,
+    x: String,
+    y: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct IapJwkSet {
+    keys: Vec<IapJwk>,
+}
+
+struct IapJwksCache {
+    keys: HashMap<String, VerifyingKey>,
+    fetched_at: Instant,
+}
+
+static IAP_JWKS: LazyLock<RwLock<Option<IapJwksCache>>> = LazyLock::new(|| RwLock::new(None));
+
+fn iap_verify_enabled() -> bool {
+    std::env::var("IAP_VERIFY_JWT")
+        .map(|v| v != "0" && v.to_lowercase() != "false")
+        .unwrap_or(true)
+}
+
+fn iap_expected_audience() -> Option<String> {
+    std::env::var("IAP_EXPECTED_AUDIENCE").ok()
+}
+
+async fn fetch_iap_jwks() -> Result<HashMap<String, VerifyingKey>> {
+    let jwk_set: IapJwkSet = reqwest::get(IAP_JWK_URL)
+        .await
+        .context("failed to fetch IAP JWKS")?
+        .json()
+        .await
+        .context("failed to parse IAP JWKS")?;
+
+    let mut keys = HashMap::new();
+    for jwk in jwk_set.keys {
+        if jwk.crv != "P-256" {
+            continue;
+        }
+        let Ok(x) = URL_SAFE_NO_PAD.decode(&jwk.x) else {
+            continue;
+        };
+        let Ok(y) = URL_SAFE_NO_PAD.decode(&jwk.y) else {
+            continue;
+        };
+        let point = p256::EncodedPoint::from_affine_coordinates(x.as_slice().into(), y.as_slice().into(), false);
+        if let Ok(key) = VerifyingKey::from_encoded_point(&point) {
+            keys.insert(jwk.kid, key);
+        }
+    }
+    Ok(keys)
+}
+
+/// Looks up the verifying key for `kid`, refreshing the cached JWK set when it's
+/// stale or the key isn't found (handles Google's periodic key rotation).
+async fn iap_verifying_key(kid: &str) -> Option<VerifyingKey> {
+    if let Ok(cache) = IAP_JWKS.read() {
+        if let Some(entry) = cache.as_ref() {
+            if entry.fetched_at.elapsed() < IAP_JWKS_REFRESH_INTERVAL {
+                if let Some(key) = entry.keys.get(kid) {
+                    return Some(*key);
+                }
+            }
+        }
+    }
+
+    match fetch_iap_jwks().await {
+        Ok(keys) => {
+            let found = keys.get(kid).copied();
+            if let Ok(mut cache) = IAP_JWKS.write() {
+                *cache = Some(IapJwksCache {
+                    keys,
+                    fetched_at: Instant::now(),
+                });
+            }
+            found
+        }
+        Err(e) => {
+            tracing::error!("Failed to refresh IAP JWKS: {:?}", e);
+            None
+        }
+    }
+}
+
+/// Verifies the ES256 signature and claims of an `x-goog-iap-jwt-assertion`
+/// header, returning the decoded claims only when the token is authentic.
+async fn verify_iap_jwt(jwt: &str) -> Option<IapContext> {
+    let parts: Vec<&str> = jwt.split('.').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let (header_b64, payload_b64, sig_b64) = (parts[0], parts[1], parts[2]);
+
+    let header: Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(header_b64).ok()?).ok()?;
+    if header.get("alg").and_then(Value::as_str) != Some("ES256") {
+        tracing::warn!("IAP JWT uses unsupported alg: {:?}", header.get("alg"));
+        return None;
+    }
+    let kid = header.get("kid").and_then(Value::as_str)?;
+
+    let verifying_key = iap_verifying_key(kid).await?;
+    let signature = Signature::from_slice(&URL_SAFE_NO_PAD.decode(sig_b64).ok()?).ok()?;
+    let signed_input = format!("{}.{}", header_b64, payload_b64);
+    if verifying_key.verify(signed_input.as_bytes(), &signature).is_err() {
+        tracing::warn!("IAP JWT signature verification failed");
+        return None;
+    }
+
+    let payload: Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload_b64).ok()?).ok()?;
+
+    if payload.get("iss").and_then(Value::as_str) != Some(IAP_ISSUER) {
+        tracing::warn!("IAP JWT has unexpected issuer: {:?}", payload.get("iss"));
+        return None;
+    }
+
+    // Every Google IAP token is signed by the same JWKS regardless of which
+    // backend/project it was minted for, so with verification enabled a
+    // missing expected audience must fail closed rather than skip the check
+    // — otherwise a valid token for a *different* project would still pass.
+    match iap_expected_audience() {
+        Some(expected_aud) => {
+            if payload.get("aud").and_then(Value::as_str) != Some(expected_aud.as_str()) {
+                tracing::warn!("IAP JWT audience mismatch");
+                return None;
+            }
+        }
+        None => {
+            tracing::error!(
+                "IAP verification is enabled but no expected_audience is configured; rejecting token"
+            );
+            return None;
+        }
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs() as i64;
+    let exp = payload.get("exp").and_then(Value::as_i64)?;
+    let iat = payload.get("iat").and_then(Value::as_i64)?;
+    if now > exp + IAP_CLOCK_SKEW_SECS || now < iat - IAP_CLOCK_SKEW_SECS {
+        tracing::warn!("IAP JWT is expired or not yet valid");
+        return None;
+    }
+
+    Some(IapContext { payload })
+}
+
 static SYSTEM_INFO_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
     LazyLock::new(|| {
         let settings = schemars::generate::SchemaSettings::draft07();
@@ -70,7 +230,86 @@ static DISK_USAGE_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value
         Arc::new(obj.clone())
     });
 
-static EXPECTED_API_KEY: OnceLock<Option<String>> = OnceLock::new();
+/// The currently accepted MCP API key plus when it was fetched, so staleness
+/// is observable (e.g. in the system-info report).
+struct ApiKeyState {
+    key: String,
+    fetched_at: Instant,
+}
+
+/// Holds the currently accepted MCP API key. Replaced wholesale by the
+/// background refresh task so a rotated key is picked up without a restart.
+static EXPECTED_API_KEY: LazyLock<Arc<RwLock<Option<ApiKeyState>>>> =
+    LazyLock::new(|| Arc::new(RwLock::new(None)));
+
+const DEFAULT_API_KEY_REFRESH_INTERVAL_SECS: u64 = 300;
+
+fn api_key_refresh_interval() -> Duration {
+    let secs = std::env::var("MCP_API_KEY_REFRESH_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_API_KEY_REFRESH_INTERVAL_SECS);
+    Duration::from_secs(secs)
+}
+
+/// Resolves the GCP project id from the GCE metadata server, falling back to
+/// `GOOGLE_CLOUD_PROJECT` for environments without a metadata server (local runs).
+async fn resolve_project_id() -> Result<String> {
+    match fetch_project_id_from_metadata().await {
+        Ok(project_id) => Ok(project_id),
+        Err(e) => {
+            tracing::debug!(
+                "Metadata server project id lookup failed (expected outside GCE): {}",
+                e
+            );
+            std::env::var("GOOGLE_CLOUD_PROJECT")
+                .context("project id not available from metadata server or GOOGLE_CLOUD_PROJECT")
+        }
+    }
+}
+
+async fn fetch_project_id_from_metadata() -> Result<String> {
+    let client = reqwest::Client::new();
+    let project_id = client
+        .get("http://metadata.google.internal/computeMetadata/v1/project/numeric-project-id")
+        .header("Metadata-Flavor", "Google")
+        .send()
+        .await
+        .context("failed to query GCE metadata server")?
+        .error_for_status()
+        .context("GCE metadata server returned an error status")?
+        .text()
+        .await
+        .context("failed to read project id from metadata server response")?;
+
+    Ok(project_id.trim().to_string())
+}
+
+/// Spawns a background task that periodically re-fetches the MCP API key and
+/// swaps it into `EXPECTED_API_KEY`, so rotations in Cloud take effect without
+/// restarting the server.
+fn spawn_api_key_refresh_task(project_id: String) {
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(api_key_refresh_interval()).await;
+            match fetch_mcp_api_key(&project_id).await {
+                Ok(key) => {
+                    tracing::info!("Refreshed MCP API Key from Cloud");
+                    *EXPECTED_API_KEY.write().unwrap() = Some(ApiKeyState {
+                        key,
+                        fetched_at: Instant::now(),
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to refresh MCP API Key: {:?}. Keeping previously cached key.",
+                        e
+                    );
+                }
+            }
+        }
+    });
+}
 
 async fn fetch_mcp_api_key(project_id: &str) -> Result<String> {
     tracing::info!("Fetching MCP API Key for project: {}", project_id);
@@ -226,12 +465,21 @@ fn collect_system_info(api_status: Option<&str>) -> String {
     let _ = writeln!(report, "IAP Context & Identity");
     let _ = writeln!(report, "----------------------");
     let _ = writeln!(report, "Header Source:    x-goog-iap-jwt-assertion");
-    let api_key_presence = if EXPECTED_API_KEY.get().and_then(|k| k.as_ref()).is_some() {
+    let api_key_state = EXPECTED_API_KEY.read().unwrap();
+    let api_key_presence = if api_key_state.is_some() {
         "Enabled (MCP_API_KEY set)"
     } else {
         "Disabled"
     };
     let _ = writeln!(report, "API Key Security: {}", api_key_presence);
+    if let Some(state) = api_key_state.as_ref() {
+        let _ = writeln!(
+            report,
+            "API Key Fetched:  {:.0}s ago",
+            state.fetched_at.elapsed().as_secs_f64()
+        );
+    }
+    drop(api_key_state);
 
     let iap_ctx = IAP_CONTEXT.try_with(|ctx| ctx.clone()).ok().flatten();
     if let Some(ctx) = iap_ctx {
@@ -384,8 +632,15 @@ async fn check_api_key_status(args: &[String]) -> (String, bool) {
     if let Some(key) = provided_key {
         let _ = writeln!(status, "Provided Key:     [FOUND]");
         // Fetch cloud key
-        let project_id = "1056842563084";
-        match fetch_mcp_api_key(project_id).await {
+        let project_id = match resolve_project_id().await {
+            Ok(id) => id,
+            Err(e) => {
+                let _ = writeln!(status, "Cloud Match:      [ERROR: {:?}]", e);
+                status.push('\n');
+                return (status, false);
+            }
+        };
+        match fetch_mcp_api_key(&project_id).await {
             Ok(expected_key) => {
                 if key == expected_key {
                     let _ = writeln!(status, "Cloud Match:      [MATCHED]");
@@ -477,13 +732,144 @@ impl ServerHandler for SysUtils {
     }
 }
 
-async fn iap_middleware(
+const HMAC_AUTH_SCHEME: &str = "MCP-HMAC-SHA256";
+const HMAC_CLOCK_SKEW_SECS: i64 = 300;
+
+fn hmac_signing_enabled() -> bool {
+    std::env::var("MCP_HMAC_AUTH")
+        .map(|v| v == "1" || v.to_lowercase() == "true")
+        .unwrap_or(false)
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Parses an `Authorization: MCP-HMAC-SHA256 keyid=...,ts=...,sig=...` header
+/// into its `(keyid, ts, sig)` fields.
+fn parse_hmac_auth_header(value: &str) -> Option<(String, i64, String)> {
+    let rest = value.strip_prefix(HMAC_AUTH_SCHEME)?.trim_start();
+    let mut keyid = None;
+    let mut ts = None;
+    let mut sig = None;
+    for field in rest.split(',') {
+        let field = field.trim();
+        if let Some(v) = field.strip_prefix("keyid=") {
+            keyid = Some(v.to_string());
+        } else if let Some(v) = field.strip_prefix("ts=") {
+            ts = v.parse::<i64>().ok();
+        } else if let Some(v) = field.strip_prefix("sig=") {
+            sig = Some(v.to_string());
+        }
+    }
+    Some((keyid?, ts?, sig?))
+}
+
+/// Builds the string the client signs: method, path, a canonicalized
+/// (sorted) query string, the hex SHA-256 digest of the body, and the
+/// timestamp, each on its own line.
+fn canonical_request(method: &str, path: &str, query: &str, body: &[u8], ts: i64) -> String {
+    let mut pairs: Vec<&str> = if query.is_empty() {
+        Vec::new()
+    } else {
+        query.split('&').collect()
+    };
+    pairs.sort_unstable();
+    let canonical_query = pairs.join("&");
+    let body_hash = to_hex(&Sha256::digest(body));
+    format!("{}\n{}\n{}\n{}\n{}", method, path, canonical_query, body_hash, ts)
+}
+
+fn hmac_sign(secret: &str, canonical: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(canonical.as_bytes());
+    to_hex(&mac.finalize().into_bytes())
+}
+
+/// Verifies an HMAC-signed request against `secret`, buffering the body so it
+/// can be hashed and then handed back intact for the MCP service downstream.
+async fn verify_hmac_signed_request(
     request: axum::extract::Request,
+    secret: &str,
+) -> Result<axum::extract::Request, axum::response::Response> {
+    use axum::response::IntoResponse;
+
+    let Some(auth_header) = request
+        .headers()
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string())
+    else {
+        tracing::warn!("HMAC auth required but Authorization header is missing or non-UTF8");
+        return Err((axum::http::StatusCode::UNAUTHORIZED, "Unauthorized").into_response());
+    };
+
+    let Some((_keyid, ts, sig)) = parse_hmac_auth_header(&auth_header) else {
+        tracing::warn!("Malformed {} Authorization header", HMAC_AUTH_SCHEME);
+        return Err((axum::http::StatusCode::UNAUTHORIZED, "Unauthorized").into_response());
+    };
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+    if (now - ts).abs() > HMAC_CLOCK_SKEW_SECS {
+        tracing::warn!("HMAC request timestamp outside the allowed replay window");
+        return Err((axum::http::StatusCode::UNAUTHORIZED, "Unauthorized").into_response());
+    }
+
+    let method = request.method().to_string();
+    let path = request.uri().path().to_string();
+    let query = request.uri().query().unwrap_or("").to_string();
+
+    let (parts, body) = request.into_parts();
+    let body_bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(b) => b,
+        Err(_) => {
+            return Err(
+                (axum::http::StatusCode::BAD_REQUEST, "failed to buffer request body").into_response(),
+            );
+        }
+    };
+
+    let canonical = canonical_request(&method, &path, &query, &body_bytes, ts);
+    let expected_sig = hmac_sign(secret, &canonical);
+
+    if !constant_time_eq(expected_sig.as_bytes(), sig.as_bytes()) {
+        tracing::warn!("HMAC signature mismatch");
+        return Err((axum::http::StatusCode::UNAUTHORIZED, "Unauthorized").into_response());
+    }
+
+    Ok(axum::extract::Request::from_parts(parts, axum::body::Body::from(body_bytes)))
+}
+
+async fn iap_middleware(
+    mut request: axum::extract::Request,
     next: axum::middleware::Next,
 ) -> axum::response::Response {
     use axum::response::IntoResponse;
 
-    if let Some(expected_key) = EXPECTED_API_KEY.get().and_then(|k| k.as_ref()) {
+    let expected_key = EXPECTED_API_KEY.read().unwrap().as_ref().map(|s| s.key.clone());
+    if let Some(expected_key) = expected_key {
+        if hmac_signing_enabled() {
+            match verify_hmac_signed_request(request, &expected_key).await {
+                Ok(req) => {
+                    request = req;
+                    tracing::debug!("HMAC request signature verified successfully");
+                    return finish_iap_middleware(request, next).await;
+                }
+                Err(resp) => return resp,
+            }
+        }
+
         let api_key_header = request
             .headers()
             .get("x-goog-api-key")
@@ -495,13 +881,25 @@ async fn iap_middleware(
                 .and_then(|p| p.get(4..))
         });
 
-        if api_key_header != Some(expected_key) && api_key_query != Some(expected_key) {
+        if api_key_header != Some(expected_key.as_str()) && api_key_query != Some(expected_key.as_str()) {
             tracing::warn!("Unauthorized request: invalid or missing API Key (checked header and ?key=)");
             return (axum::http::StatusCode::UNAUTHORIZED, "Unauthorized").into_response();
         }
         tracing::debug!("API Key verified successfully");
     }
 
+    finish_iap_middleware(request, next).await
+}
+
+/// Decodes/verifies the IAP JWT (if present) and runs the rest of the
+/// pipeline with `IAP_CONTEXT`/`REQUEST_HEADERS` populated. Shared by both
+/// the plaintext-key and HMAC-signed authentication paths above.
+async fn finish_iap_middleware(
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    use axum::response::IntoResponse;
+
     let mut headers = Vec::new();
     for (name, value) in request.headers() {
         headers.push((
@@ -521,15 +919,27 @@ async fn iap_middleware(
 
     if let Some(header_value) = iap_header {
         tracing::debug!("Found x-goog-iap-jwt-assertion header");
-        if let Ok(jwt_str) = header_value.to_str() {
-            if let Some(ctx) = decode_iap_jwt(jwt_str) {
-                tracing::info!("IAP JWT decoded successfully. Claims: {}", ctx.payload);
-                iap_context = Some(ctx);
-            } else {
-                tracing::error!("Failed to decode x-goog-iap-jwt-assertion payload");
+        let Ok(jwt_str) = header_value.to_str() else {
+            tracing::error!("x-goog-iap-jwt-assertion header contains non-UTF8 data");
+            return (axum::http::StatusCode::UNAUTHORIZED, "Unauthorized").into_response();
+        };
+
+        if iap_verify_enabled() {
+            match verify_iap_jwt(jwt_str).await {
+                Some(ctx) => {
+                    tracing::info!("IAP JWT verified successfully. Claims: {}", ctx.payload);
+                    iap_context = Some(ctx);
+                }
+                None => {
+                    tracing::warn!("IAP JWT failed signature/claim verification");
+                    return (axum::http::StatusCode::UNAUTHORIZED, "Unauthorized").into_response();
+                }
             }
+        } else if let Some(ctx) = decode_iap_jwt(jwt_str) {
+            tracing::info!("IAP JWT decoded (verification disabled). Claims: {}", ctx.payload);
+            iap_context = Some(ctx);
         } else {
-            tracing::error!("x-goog-iap-jwt-assertion header contains non-UTF8 data");
+            tracing::error!("Failed to decode x-goog-iap-jwt-assertion payload");
         }
     } else {
         tracing::debug!("No x-goog-iap-jwt-assertion header found");
@@ -540,6 +950,67 @@ async fn iap_middleware(
         .await
 }
 
+#[derive(serde::Serialize)]
+struct AdminToolInfo {
+    name: String,
+    description: String,
+    input_schema: Arc<serde_json::Map<String, serde_json::Value>>,
+}
+
+fn admin_enabled() -> bool {
+    std::env::var("ADMIN_ENABLED")
+        .map(|v| v != "0" && v.to_lowercase() != "false")
+        .unwrap_or(true)
+}
+
+/// Lists the tools registered on `SysUtils`'s `ToolRouter`, read straight off
+/// the router built by `#[tool_router]` so this can't drift out of sync with
+/// the `#[tool(...)]` definitions the way a hand-maintained manifest could.
+async fn admin_tools_handler() -> axum::Json<Vec<AdminToolInfo>> {
+    let tools = SysUtils::tool_router()
+        .list_all()
+        .into_iter()
+        .map(|tool| AdminToolInfo {
+            name: tool.name.to_string(),
+            description: tool.description.map(|d| d.to_string()).unwrap_or_default(),
+            input_schema: Arc::new((*tool.input_schema).clone()),
+        })
+        .collect();
+    axum::Json(tools)
+}
+
+async fn admin_iap_handler() -> axum::Json<serde_json::Value> {
+    let ctx = IAP_CONTEXT.try_with(|ctx| ctx.clone()).ok().flatten();
+    axum::Json(match ctx {
+        Some(ctx) => ctx.payload,
+        None => serde_json::json!({ "iap_context": null }),
+    })
+}
+
+async fn admin_keystatus_handler() -> axum::Json<serde_json::Value> {
+    let (status, success) = check_api_key_status(&[]).await;
+    axum::Json(serde_json::json!({ "status": status, "ok": success }))
+}
+
+/// Declares an HTTP method, path, and handler together in one place, in the
+/// style of Garage's `router_macros` — adding an `/admin` route means adding
+/// one line here rather than a separate `.route(...)` call that can drift
+/// out of sync with the handler list.
+macro_rules! admin_routes {
+    ($($method:ident $path:expr => $handler:expr),+ $(,)?) => {
+        axum::Router::new()
+            $(.route($path, axum::routing::$method($handler)))+
+    };
+}
+
+fn build_admin_router() -> axum::Router {
+    admin_routes! {
+        get "/admin/tools" => admin_tools_handler,
+        get "/admin/iap" => admin_iap_handler,
+        get "/admin/keystatus" => admin_keystatus_handler,
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Check for CLI arguments
@@ -573,8 +1044,10 @@ async fn main() -> Result<()> {
     }
 
     // Initialize EXPECTED_API_KEY for the server
-    let project_id = "1056842563084";
-    let cloud_key = match fetch_mcp_api_key(project_id).await {
+    let project_id = resolve_project_id()
+        .await
+        .context("could not determine GCP project id (metadata server and GOOGLE_CLOUD_PROJECT both unavailable)")?;
+    let cloud_key = match fetch_mcp_api_key(&project_id).await {
         Ok(key) => {
             tracing::info!("Successfully fetched MCP API Key from Cloud");
             Some(key)
@@ -586,7 +1059,11 @@ async fn main() -> Result<()> {
     };
 
     let cloud_key = cloud_key.context("MCP_API_KEY not found in Cloud or environment. Server requires an API key.")?;
-    EXPECTED_API_KEY.set(Some(cloud_key)).ok();
+    *EXPECTED_API_KEY.write().unwrap() = Some(ApiKeyState {
+        key: cloud_key,
+        fetched_at: Instant::now(),
+    });
+    spawn_api_key_refresh_task(project_id);
 
     let service_factory = || Ok(SysUtils::new());
     let session_manager = LocalSessionManager::default();
@@ -595,10 +1072,15 @@ async fn main() -> Result<()> {
     let service = StreamableHttpService::new(service_factory, session_manager.into(), config);
 
     // Add a specific health check route and IAP middleware
-    let app = axum::Router::new()
+    let mut app = axum::Router::new()
         .route("/health", axum::routing::get(|| async { "ok" }))
-        .fallback_service(service)
-        .layer(axum::middleware::from_fn(iap_middleware));
+        .fallback_service(service);
+
+    if admin_enabled() {
+        app = app.merge(build_admin_router());
+    }
+
+    let app = app.layer(axum::middleware::from_fn(iap_middleware));
 
     // Determine port from environment variable (Cloud Run standard)
     let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
@@ -699,6 +1181,68 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_verify_iap_jwt_checks_signature() {
+        use ecdsa::signature::Signer;
+        use p256::ecdsa::SigningKey;
+
+        let signing_key = SigningKey::random(&mut rand_core::OsRng);
+        let verifying_key = *signing_key.verifying_key();
+        let kid = "test-kid";
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        let header = serde_json::json!({"alg": "ES256", "kid": kid});
+        let payload = serde_json::json!({
+            "iss": IAP_ISSUER,
+            "aud": "test-audience",
+            "email": "test@example.com",
+            "exp": now + 3600,
+            "iat": now,
+        });
+        let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_string(&header).unwrap());
+        let payload_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_string(&payload).unwrap());
+        let signed_input = format!("{}.{}", header_b64, payload_b64);
+        let signature: Signature = signing_key.sign(signed_input.as_bytes());
+        let jwt = format!(
+            "{}.{}",
+            signed_input,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        );
+
+        {
+            let mut cache = IAP_JWKS.write().unwrap();
+            *cache = Some(IapJwksCache {
+                keys: HashMap::from([(kid.to_string(), verifying_key)]),
+                fetched_at: Instant::now(),
+            });
+        }
+
+        // verify_iap_jwt fails closed without a configured expected audience.
+        std::env::set_var("IAP_EXPECTED_AUDIENCE", "test-audience");
+
+        let ctx = verify_iap_jwt(&jwt).await.unwrap();
+        assert_eq!(
+            ctx.payload.get("email").unwrap().as_str().unwrap(),
+            "test@example.com"
+        );
+
+        // Tampering with the payload must invalidate the signature.
+        let mut tampered_payload = payload.clone();
+        tampered_payload["email"] = serde_json::json!("attacker@example.com");
+        let tampered_payload_b64 =
+            URL_SAFE_NO_PAD.encode(serde_json::to_string(&tampered_payload).unwrap());
+        let tampered_jwt = format!(
+            "{}.{}.{}",
+            header_b64,
+            tampered_payload_b64,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        );
+        assert!(verify_iap_jwt(&tampered_jwt).await.is_none());
+    }
+
     #[tokio::test]
     async fn test_collect_system_info_with_context() {
         let payload = serde_json::json!({
@@ -719,4 +1263,47 @@ mod tests {
         assert!(report.contains("custom_field      : custom_value"));
         assert!(report.contains("user-agent        : test-agent"));
     }
+
+    #[tokio::test]
+    async fn test_admin_tools_handler_lists_registered_tools() {
+        let axum::Json(tools) = admin_tools_handler().await;
+        let names: Vec<&str> = tools.iter().map(|t| t.name.as_str()).collect();
+        assert!(names.contains(&"local_system_info"));
+        assert!(names.contains(&"disk_usage"));
+    }
+
+    #[tokio::test]
+    async fn test_admin_iap_handler_reports_null_without_context() {
+        let axum::Json(value) = IAP_CONTEXT.scope(None, admin_iap_handler()).await;
+        assert_eq!(value, serde_json::json!({ "iap_context": null }));
+    }
+
+    #[test]
+    fn test_parse_hmac_auth_header() {
+        let (keyid, ts, sig) =
+            parse_hmac_auth_header("MCP-HMAC-SHA256 keyid=prod,ts=1700000000,sig=abcd1234").unwrap();
+        assert_eq!(keyid, "prod");
+        assert_eq!(ts, 1700000000);
+        assert_eq!(sig, "abcd1234");
+
+        assert!(parse_hmac_auth_header("Bearer sometoken").is_none());
+    }
+
+    #[test]
+    fn test_hmac_sign_is_deterministic_and_tamper_evident() {
+        let canonical = canonical_request("GET", "/health", "", b"", 1700000000);
+        let sig_a = hmac_sign("shared-secret", &canonical);
+        let sig_b = hmac_sign("shared-secret", &canonical);
+        assert_eq!(sig_a, sig_b);
+
+        let tampered = canonical_request("GET", "/health", "a=1", b"", 1700000000);
+        assert_ne!(sig_a, hmac_sign("shared-secret", &tampered));
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"match", b"match"));
+        assert!(!constant_time_eq(b"match", b"mismatch"));
+        assert!(!constant_time_eq(b"match", b"matc1"));
+    }
 }
@@ -7,6 +7,9 @@ use axum::{
     response::Response,
     Extension,
 };
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use ecdsa::signature::Verifier;
+use p256::ecdsa::{Signature, VerifyingKey};
 use rmcp::{
     handler::server::{ServerHandler, tool::ToolRouter, wrapper::Parameters},
     model::{ServerCapabilities, ServerInfo},
@@ -15,27 +18,371 @@ use rmcp::{
         StreamableHttpServerConfig, StreamableHttpService, session::local::LocalSessionManager,
     },
 };
+use serde_json::Value;
 use sysinfo::System;
+use tokio::io::AsyncReadExt;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use uuid::Uuid;
 
-use std::sync::{Arc, LazyLock};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex as StdMutex, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 // Google Cloud Dependencies
 use google_apikeys2::ApiKeysService;
 use yup_oauth2::authenticator::ApplicationDefaultCredentialsTypes;
 use yup_oauth2::ApplicationDefaultCredentialsAuthenticator;
 
-#[derive(Clone)]
-struct ApiKey(Arc<Option<String>>);
+/// One entry in the API key registry: who the key belongs to, when (if ever)
+/// it stops being valid, and which tools it's scoped to call.
+#[derive(Clone, Debug)]
+struct ApiKeyEntry {
+    display_name: String,
+    not_after: Option<i64>,
+    allowed_tools: HashSet<String>,
+}
+
+impl ApiKeyEntry {
+    fn is_expired(&self) -> bool {
+        match self.not_after {
+            Some(not_after) => now_unix() > not_after,
+            None => false,
+        }
+    }
+}
+
+fn now_unix() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[derive(Clone, Default)]
+struct KeyRegistry(Arc<HashMap<String, ApiKeyEntry>>);
+
+tokio::task_local! {
+    static ALLOWED_TOOLS: Option<HashSet<String>>;
+    static IAP_CONTEXT: Option<IapContext>;
+    static REQUEST_HEADERS: Vec<(String, String)>;
+}
+
+/// Decoded (and, when `IAP_VERIFY_JWT` is enabled, signature-checked) claims
+/// from an `x-goog-iap-jwt-assertion` header.
+#[derive(Debug, Clone, serde::Serialize)]
+struct IapContext {
+    payload: Value,
+}
+
+/// Base64url-decodes the JWT payload without checking the signature. Used only
+/// when `IAP_VERIFY_JWT=0`, e.g. for local runs without a real IAP in front.
+fn decode_iap_jwt(jwt: &str) -> Option<IapContext> {
+    let parts: Vec<&str> = jwt.split('.').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let decoded = URL_SAFE_NO_PAD.decode(parts[1]).ok()?;
+    let payload: Value = serde_json::from_slice(&decoded).ok()?;
+    Some(IapContext { payload })
+}
+
+const IAP_JWK_URL: &str = "https://www.gstatic.com/iap/verify/public_key-jwk";
+const IAP_ISSUER: &str = "https://cloud.google.com/iap";
+const IAP_CLOCK_SKEW_SECS: i64 = 30;
+const IAP_JWKS_REFRESH_INTERVAL: Duration = Duration::from_secs(3600);
+
+#[derive(Debug, serde::Deserialize)]
+struct IapJwk {
+    kid: String,
+    crv: String,
+    x: String,
+    y: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct IapJwkSet {
+    keys: Vec<IapJwk>,
+}
+
+struct IapJwksCache {
+    keys: HashMap<String, VerifyingKey>,
+    fetched_at: Instant,
+}
+
+static IAP_JWKS: LazyLock<RwLock<Option<IapJwksCache>>> = LazyLock::new(|| RwLock::new(None));
+
+fn iap_verify_enabled() -> bool {
+    std::env::var("IAP_VERIFY_JWT")
+        .map(|v| v != "0" && v.to_lowercase() != "false")
+        .unwrap_or(true)
+}
+
+fn iap_expected_audience() -> Option<String> {
+    std::env::var("IAP_EXPECTED_AUDIENCE").ok()
+}
+
+async fn fetch_iap_jwks() -> Result<HashMap<String, VerifyingKey>> {
+    let jwk_set: IapJwkSet = reqwest::get(IAP_JWK_URL)
+        .await
+        .context("failed to fetch IAP JWKS")?
+        .json()
+        .await
+        .context("failed to parse IAP JWKS")?;
+
+    let mut keys = HashMap::new();
+    for jwk in jwk_set.keys {
+        if jwk.crv != "P-256" {
+            continue;
+        }
+        let Ok(x) = URL_SAFE_NO_PAD.decode(&jwk.x) else {
+            continue;
+        };
+        let Ok(y) = URL_SAFE_NO_PAD.decode(&jwk.y) else {
+            continue;
+        };
+        let point =
+            p256::EncodedPoint::from_affine_coordinates(x.as_slice().into(), y.as_slice().into(), false);
+        if let Ok(key) = VerifyingKey::from_encoded_point(&point) {
+            keys.insert(jwk.kid, key);
+        }
+    }
+    Ok(keys)
+}
+
+/// Looks up the verifying key for `kid`, refreshing the cached JWK set when it's
+/// stale or the key isn't found (handles Google's periodic key rotation).
+async fn iap_verifying_key(kid: &str) -> Option<VerifyingKey> {
+    if let Ok(cache) = IAP_JWKS.read() {
+        if let Some(entry) = cache.as_ref() {
+            if entry.fetched_at.elapsed() < IAP_JWKS_REFRESH_INTERVAL {
+                if let Some(key) = entry.keys.get(kid) {
+                    return Some(*key);
+                }
+            }
+        }
+    }
+
+    match fetch_iap_jwks().await {
+        Ok(keys) => {
+            let found = keys.get(kid).copied();
+            if let Ok(mut cache) = IAP_JWKS.write() {
+                *cache = Some(IapJwksCache {
+                    keys,
+                    fetched_at: Instant::now(),
+                });
+            }
+            found
+        }
+        Err(e) => {
+            tracing::error!("Failed to refresh IAP JWKS: {:?}", e);
+            None
+        }
+    }
+}
+
+/// Verifies the ES256 signature and claims of an `x-goog-iap-jwt-assertion`
+/// header, returning the decoded claims only when the token is authentic.
+async fn verify_iap_jwt(jwt: &str) -> Option<IapContext> {
+    let parts: Vec<&str> = jwt.split('.').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let (header_b64, payload_b64, sig_b64) = (parts[0], parts[1], parts[2]);
+
+    let header: Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(header_b64).ok()?).ok()?;
+    if header.get("alg").and_then(Value::as_str) != Some("ES256") {
+        tracing::warn!("IAP JWT uses unsupported alg: {:?}", header.get("alg"));
+        return None;
+    }
+    let kid = header.get("kid").and_then(Value::as_str)?;
+
+    let verifying_key = iap_verifying_key(kid).await?;
+    let signature = Signature::from_slice(&URL_SAFE_NO_PAD.decode(sig_b64).ok()?).ok()?;
+    let signed_input = format!("{}.{}", header_b64, payload_b64);
+    if verifying_key.verify(signed_input.as_bytes(), &signature).is_err() {
+        tracing::warn!("IAP JWT signature verification failed");
+        return None;
+    }
+
+    let payload: Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload_b64).ok()?).ok()?;
+
+    if payload.get("iss").and_then(Value::as_str) != Some(IAP_ISSUER) {
+        tracing::warn!("IAP JWT has unexpected issuer: {:?}", payload.get("iss"));
+        return None;
+    }
+
+    // Every Google IAP token is signed by the same JWKS regardless of which
+    // backend/project it was minted for, so with verification enabled a
+    // missing expected audience must fail closed rather than skip the check
+    // — otherwise a valid token for a *different* project would still pass.
+    match iap_expected_audience() {
+        Some(expected_aud) => {
+            if payload.get("aud").and_then(Value::as_str) != Some(expected_aud.as_str()) {
+                tracing::warn!("IAP JWT audience mismatch");
+                return None;
+            }
+        }
+        None => {
+            tracing::error!(
+                "IAP verification is enabled but no expected_audience is configured; rejecting token"
+            );
+            return None;
+        }
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs() as i64;
+    let exp = payload.get("exp").and_then(Value::as_i64)?;
+    let iat = payload.get("iat").and_then(Value::as_i64)?;
+    if now > exp + IAP_CLOCK_SKEW_SECS || now < iat - IAP_CLOCK_SKEW_SECS {
+        tracing::warn!("IAP JWT is expired or not yet valid");
+        return None;
+    }
+
+    Some(IapContext { payload })
+}
+
+/// Cloud API Keys `display_name` encodes scope as `;`-separated `field=value`
+/// pairs, e.g. `name=dashboard;tools=list_processes,disk_usage;exp=1735689600`.
+/// Unrecognized fields are ignored so plain display names still work (with no
+/// scope restriction and no expiry).
+fn parse_key_entry(display_name: &str) -> ApiKeyEntry {
+    let mut label = display_name.to_string();
+    let mut allowed_tools = HashSet::new();
+    let mut not_after = None;
+
+    for field in display_name.split(';') {
+        if let Some(tools) = field.strip_prefix("tools=") {
+            allowed_tools = tools
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        } else if let Some(exp) = field.strip_prefix("exp=") {
+            not_after = exp.trim().parse::<i64>().ok();
+        } else if let Some(name) = field.strip_prefix("name=") {
+            label = name.to_string();
+        }
+    }
+
+    ApiKeyEntry {
+        display_name: label,
+        not_after,
+        allowed_tools,
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiKeyOverride {
+    key: String,
+    display_name: String,
+    #[serde(default)]
+    not_after: Option<i64>,
+    #[serde(default)]
+    allowed_tools: Vec<String>,
+}
+
+/// Merges local-testing overrides from `MCP_API_KEYS_JSON` (a JSON array of
+/// `{key, display_name, not_after, allowed_tools}`) into the registry.
+fn load_env_key_overrides(registry: &mut HashMap<String, ApiKeyEntry>) {
+    let Ok(raw) = std::env::var("MCP_API_KEYS_JSON") else {
+        return;
+    };
+    match serde_json::from_str::<Vec<ApiKeyOverride>>(&raw) {
+        Ok(overrides) => {
+            for o in overrides {
+                registry.insert(
+                    o.key,
+                    ApiKeyEntry {
+                        display_name: o.display_name,
+                        not_after: o.not_after,
+                        allowed_tools: o.allowed_tools.into_iter().collect(),
+                    },
+                );
+            }
+        }
+        Err(e) => tracing::warn!("Failed to parse MCP_API_KEYS_JSON override: {:?}", e),
+    }
+}
+
+/// Output mode shared by the reporting tools: `text` keeps the existing
+/// human-readable report, `json` emits the equivalent result struct.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
 
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
-struct IapSystemInfoRequest {}
+struct IapSystemInfoRequest {
+    #[serde(default)]
+    format: OutputFormat,
+}
 
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
-struct DiskUsageRequest {}
+struct DiskUsageRequest {
+    #[serde(default)]
+    format: OutputFormat,
+}
 
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
-struct ProcessListRequest {}
+struct ProcessListRequest {
+    #[serde(default)]
+    format: OutputFormat,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+struct NetworkConnectionsRequest {}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+struct SystemMonitorRequest {
+    /// How many processes to report, sorted by `sort_by`. Defaults to 10.
+    #[serde(default)]
+    top_n: Option<usize>,
+    /// `"cpu"` (default) or `"memory"`.
+    #[serde(default)]
+    sort_by: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+struct SignalProcessRequest {
+    pid: u32,
+    /// POSIX signal number to send, e.g. 15 for SIGTERM or 9 for SIGKILL.
+    /// Defaults to 15. Only signals in the configured allowlist are sent.
+    #[serde(default)]
+    signal: Option<i32>,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+struct SpawnProcessRequest {
+    command: String,
+    #[serde(default)]
+    args: Vec<String>,
+    /// How long to wait for the command to finish before returning a
+    /// "still running" result instead of its exit status. Defaults to 10s;
+    /// hard-capped at 120s. The process itself is not killed when this
+    /// elapses — check back with `process_status`/`process_wait`.
+    #[serde(default)]
+    timeout_secs: Option<u64>,
+    /// Maximum bytes of stdout/stderr to retain. Defaults to 64KiB;
+    /// hard-capped at 1MiB. Output beyond the cap is discarded, not buffered.
+    #[serde(default)]
+    max_output_bytes: Option<usize>,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+struct ProcessStatusRequest {
+    id: String,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+struct ProcessWaitRequest {
+    id: String,
+    #[serde(default)]
+    timeout_secs: Option<u64>,
+}
 
 static SYSTEM_INFO_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
     LazyLock::new(|| {
@@ -70,9 +417,423 @@ static PROCESS_LIST_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Val
         Arc::new(obj.clone())
     });
 
+static NETWORK_CONNECTIONS_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
+    LazyLock::new(|| {
+        let settings = schemars::generate::SchemaSettings::draft07();
+        let generator = settings.into_generator();
+        let schema = generator.into_root_schema_for::<NetworkConnectionsRequest>();
+        let mut val = serde_json::to_value(schema).unwrap();
+        let obj = val.as_object_mut().unwrap();
+        obj.remove("$schema");
+        Arc::new(obj.clone())
+    });
+
+static SYSTEM_MONITOR_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
+    LazyLock::new(|| {
+        let settings = schemars::generate::SchemaSettings::draft07();
+        let generator = settings.into_generator();
+        let schema = generator.into_root_schema_for::<SystemMonitorRequest>();
+        let mut val = serde_json::to_value(schema).unwrap();
+        let obj = val.as_object_mut().unwrap();
+        obj.remove("$schema");
+        Arc::new(obj.clone())
+    });
+
+static SIGNAL_PROCESS_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
+    LazyLock::new(|| {
+        let settings = schemars::generate::SchemaSettings::draft07();
+        let generator = settings.into_generator();
+        let schema = generator.into_root_schema_for::<SignalProcessRequest>();
+        let mut val = serde_json::to_value(schema).unwrap();
+        let obj = val.as_object_mut().unwrap();
+        obj.remove("$schema");
+        Arc::new(obj.clone())
+    });
+
+static SPAWN_PROCESS_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
+    LazyLock::new(|| {
+        let settings = schemars::generate::SchemaSettings::draft07();
+        let generator = settings.into_generator();
+        let schema = generator.into_root_schema_for::<SpawnProcessRequest>();
+        let mut val = serde_json::to_value(schema).unwrap();
+        let obj = val.as_object_mut().unwrap();
+        obj.remove("$schema");
+        Arc::new(obj.clone())
+    });
+
+static PROCESS_STATUS_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
+    LazyLock::new(|| {
+        let settings = schemars::generate::SchemaSettings::draft07();
+        let generator = settings.into_generator();
+        let schema = generator.into_root_schema_for::<ProcessStatusRequest>();
+        let mut val = serde_json::to_value(schema).unwrap();
+        let obj = val.as_object_mut().unwrap();
+        obj.remove("$schema");
+        Arc::new(obj.clone())
+    });
+
+static PROCESS_WAIT_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
+    LazyLock::new(|| {
+        let settings = schemars::generate::SchemaSettings::draft07();
+        let generator = settings.into_generator();
+        let schema = generator.into_root_schema_for::<ProcessWaitRequest>();
+        let mut val = serde_json::to_value(schema).unwrap();
+        let obj = val.as_object_mut().unwrap();
+        obj.remove("$schema");
+        Arc::new(obj.clone())
+    });
+
+#[derive(Debug, serde::Serialize)]
+struct CpuReport {
+    cores: usize,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct MemoryReport {
+    total_mb: u64,
+    used_mb: u64,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SwapReport {
+    total_mb: u64,
+    used_mb: u64,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct NetworkInterfaceReport {
+    name: String,
+    rx_bytes: u64,
+    tx_bytes: u64,
+    mac_address: String,
+}
+
+/// Structured equivalent of `collect_system_info`, for `format: "json"` calls.
+#[derive(Debug, serde::Serialize)]
+struct SystemInfoReport {
+    host_name: String,
+    kernel_version: String,
+    os_version: String,
+    cpu: CpuReport,
+    memory: MemoryReport,
+    swap: SwapReport,
+    networks: Vec<NetworkInterfaceReport>,
+    /// Claims from the `x-goog-iap-jwt-assertion`, if one was present (only
+    /// decoded, not verified, when `IAP_VERIFY_JWT` is off).
+    iap_identity: Option<serde_json::Value>,
+    request_headers: Vec<(String, String)>,
+}
+
+fn gather_system_info_report() -> SystemInfoReport {
+    let mut sys = System::new_all();
+    sys.refresh_all();
+
+    let networks = sysinfo::Networks::new_with_refreshed_list();
+    let network_reports = networks
+        .iter()
+        .map(|(name, network)| NetworkInterfaceReport {
+            name: name.clone(),
+            rx_bytes: network.total_received(),
+            tx_bytes: network.total_transmitted(),
+            mac_address: network.mac_address().to_string(),
+        })
+        .collect();
+
+    let iap_identity = IAP_CONTEXT
+        .try_with(|ctx| ctx.clone())
+        .ok()
+        .flatten()
+        .map(|ctx| ctx.payload);
+    let request_headers = REQUEST_HEADERS.try_with(|h| h.clone()).unwrap_or_default();
+
+    SystemInfoReport {
+        host_name: System::host_name().unwrap_or_else(|| "<unknown>".to_string()),
+        kernel_version: System::kernel_version().unwrap_or_else(|| "<unknown>".to_string()),
+        os_version: System::os_version().unwrap_or_else(|| "<unknown>".to_string()),
+        cpu: CpuReport { cores: sys.cpus().len() },
+        memory: MemoryReport {
+            total_mb: sys.total_memory() / 1024 / 1024,
+            used_mb: sys.used_memory() / 1024 / 1024,
+        },
+        swap: SwapReport {
+            total_mb: sys.total_swap() / 1024 / 1024,
+            used_mb: sys.used_swap() / 1024 / 1024,
+        },
+        networks: network_reports,
+        iap_identity,
+        request_headers,
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DiskEntry {
+    mount_point: String,
+    file_system: String,
+    used_mb: u64,
+    total_mb: u64,
+    usage_percent: f64,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DiskReport {
+    disks: Vec<DiskEntry>,
+}
+
+/// Structured equivalent of the `disk_usage` tool's text report.
+fn gather_disk_report() -> DiskReport {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+
+    let entries = disks
+        .iter()
+        .map(|disk| {
+            let total = disk.total_space();
+            let available = disk.available_space();
+            let used = total - available;
+            let usage_percent = if total > 0 {
+                (used as f64 / total as f64) * 100.0
+            } else {
+                0.0
+            };
+
+            DiskEntry {
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                file_system: disk.file_system().to_string_lossy().to_string(),
+                used_mb: used / 1024 / 1024,
+                total_mb: total / 1024 / 1024,
+                usage_percent,
+            }
+        })
+        .collect();
+
+    DiskReport { disks: entries }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ProcessEntry {
+    pid: u32,
+    name: String,
+    memory_kb: u64,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ProcessListReport {
+    processes: Vec<ProcessEntry>,
+}
+
+/// Structured equivalent of the `list_processes` tool's text report (same
+/// top-20-by-memory cutoff as the text path, to avoid divergence).
+fn gather_process_report() -> ProcessListReport {
+    let mut sys = System::new_all();
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+
+    let mut processes: Vec<_> = sys.processes().values().collect();
+    processes.sort_by_key(|p| p.memory());
+    processes.reverse();
+
+    let entries = processes
+        .iter()
+        .take(20)
+        .map(|process| ProcessEntry {
+            pid: process.pid().as_u32(),
+            name: process.name().to_string_lossy().to_string(),
+            memory_kb: process.memory() / 1024,
+        })
+        .collect();
+
+    ProcessListReport { processes: entries }
+}
+
+/// Best-effort caller identity for audit logging, pulled from the IAP JWT
+/// claims populated by `iap_middleware`. Falls back to a fixed label so
+/// every management action still gets logged even without IAP in front.
+fn iap_identity_summary() -> String {
+    IAP_CONTEXT
+        .try_with(|ctx| ctx.clone())
+        .ok()
+        .flatten()
+        .and_then(|ctx| {
+            ctx.payload
+                .get("email")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| "unauthenticated".to_string())
+}
+
+/// Signals `signal_process` will send without requiring an operator override
+/// via `MCP_SIGNAL_ALLOWLIST` (comma-separated signal numbers). Covers the
+/// common graceful/forceful termination and reload signals while excluding
+/// anything that could crash-dump or ptrace-attach the target.
+const DEFAULT_ALLOWED_SIGNALS: &[i32] = &[1, 2, 9, 15]; // SIGHUP, SIGINT, SIGKILL, SIGTERM
+
+fn allowed_signals() -> Vec<i32> {
+    match std::env::var("MCP_SIGNAL_ALLOWLIST") {
+        Ok(v) => v.split(',').filter_map(|s| s.trim().parse::<i32>().ok()).collect(),
+        Err(_) => DEFAULT_ALLOWED_SIGNALS.to_vec(),
+    }
+}
+
+/// Sends `signal` to `pid` via the `kill` CLI after checking it against the
+/// allowlist and refusing to touch PID 1 or this server's own process.
+async fn send_signal(pid: u32, signal: i32) -> Result<()> {
+    if pid == 1 {
+        anyhow::bail!("refusing to signal PID 1 (init)");
+    }
+    if pid == std::process::id() {
+        anyhow::bail!("refusing to signal the server's own process");
+    }
+    if !allowed_signals().contains(&signal) {
+        anyhow::bail!("signal {} is not in the allowlist", signal);
+    }
+
+    let status = tokio::process::Command::new("kill")
+        .arg(format!("-{}", signal))
+        .arg(pid.to_string())
+        .status()
+        .await
+        .context("failed to execute kill")?;
+
+    if !status.success() {
+        anyhow::bail!("kill exited with status {}", status);
+    }
+    Ok(())
+}
+
+const SPAWN_DEFAULT_TIMEOUT_SECS: u64 = 10;
+const SPAWN_MAX_TIMEOUT_SECS: u64 = 120;
+const SPAWN_DEFAULT_MAX_OUTPUT_BYTES: usize = 64 * 1024;
+const SPAWN_MAX_MAX_OUTPUT_BYTES: usize = 1024 * 1024;
+
+/// A process started by `spawn_process`, tracked so `process_status`/
+/// `process_wait` can poll or reap it after the initial call returns.
+struct ChildHandle {
+    command: String,
+    args: Vec<String>,
+    pid: Option<u32>,
+    started_at: Instant,
+    started_by: String,
+    stdout: Arc<StdMutex<Vec<u8>>>,
+    stdout_truncated: Arc<AtomicBool>,
+    stderr: Arc<StdMutex<Vec<u8>>>,
+    stderr_truncated: Arc<AtomicBool>,
+    exit_code: Arc<StdMutex<Option<i32>>>,
+    done: Arc<tokio::sync::Notify>,
+}
+
+impl ChildHandle {
+    fn to_report(&self, id: Uuid) -> ChildStatusReport {
+        let exit_code = *self.exit_code.lock().unwrap();
+        ChildStatusReport {
+            id,
+            command: self.command.clone(),
+            args: self.args.clone(),
+            pid: self.pid,
+            running: exit_code.is_none(),
+            exit_code,
+            stdout: String::from_utf8_lossy(&self.stdout.lock().unwrap()).into_owned(),
+            stdout_truncated: self.stdout_truncated.load(Ordering::Relaxed),
+            stderr: String::from_utf8_lossy(&self.stderr.lock().unwrap()).into_owned(),
+            stderr_truncated: self.stderr_truncated.load(Ordering::Relaxed),
+            started_by: self.started_by.clone(),
+            elapsed_secs: self.started_at.elapsed().as_secs_f64(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ChildStatusReport {
+    id: Uuid,
+    command: String,
+    args: Vec<String>,
+    pid: Option<u32>,
+    running: bool,
+    exit_code: Option<i32>,
+    stdout: String,
+    stdout_truncated: bool,
+    stderr: String,
+    stderr_truncated: bool,
+    started_by: String,
+    elapsed_secs: f64,
+}
+
+/// Process-wide registry of children spawned via `spawn_process`, shared by
+/// every `SysUtils` instance (one is constructed per MCP session) so status
+/// lookups and shutdown cleanup see the same set regardless of which
+/// session's tool call is asking.
+static CHILD_PROCESSES: LazyLock<Arc<tokio::sync::Mutex<HashMap<Uuid, ChildHandle>>>> =
+    LazyLock::new(|| Arc::new(tokio::sync::Mutex::new(HashMap::new())));
+
+/// Drains a child's stdout/stderr pipe into a capped in-memory buffer so the
+/// process can't be used to exhaust server memory via runaway output.
+fn spawn_output_reader<R>(mut reader: R, buf: Arc<StdMutex<Vec<u8>>>, truncated: Arc<AtomicBool>, cap: usize)
+where
+    R: tokio::io::AsyncRead + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut chunk = [0u8; 4096];
+        loop {
+            match reader.read(&mut chunk).await {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    let mut guard = buf.lock().unwrap();
+                    let remaining = cap.saturating_sub(guard.len());
+                    let take = n.min(remaining);
+                    guard.extend_from_slice(&chunk[..take]);
+                    if take < n {
+                        truncated.store(true, Ordering::Relaxed);
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Waits (bounded by `timeout`) for a tracked child to finish, then returns
+/// its current report. A child that's already finished by the time this is
+/// called is reported immediately; one that's still running after `timeout`
+/// is reported as `running: true` rather than erroring.
+async fn wait_for_child(
+    children: &tokio::sync::Mutex<HashMap<Uuid, ChildHandle>>,
+    id: Uuid,
+    timeout: Duration,
+) -> Option<ChildStatusReport> {
+    let done = {
+        let guard = children.lock().await;
+        let handle = guard.get(&id)?;
+        if handle.exit_code.lock().unwrap().is_some() {
+            return Some(handle.to_report(id));
+        }
+        handle.done.clone()
+    };
+    let _ = tokio::time::timeout(timeout, done.notified()).await;
+
+    let guard = children.lock().await;
+    Some(guard.get(&id)?.to_report(id))
+}
+
+/// Sends SIGTERM to any processes `spawn_process` is still tracking so they
+/// don't outlive the server on shutdown.
+async fn reap_child_processes() {
+    let mut children = CHILD_PROCESSES.lock().await;
+    for (id, handle) in children.drain() {
+        if handle.exit_code.lock().unwrap().is_some() {
+            continue;
+        }
+        if let Some(pid) = handle.pid {
+            tracing::info!("Terminating spawned process {} (pid {}) on shutdown", id, pid);
+            let _ = tokio::process::Command::new("kill")
+                .arg("-15")
+                .arg(pid.to_string())
+                .status()
+                .await;
+        }
+    }
+}
+
 #[derive(Clone)]
 struct SysUtils {
     tool_router: ToolRouter<Self>,
+    children: Arc<tokio::sync::Mutex<HashMap<Uuid, ChildHandle>>>,
 }
 
 async fn fetch_mcp_api_key(project_id: &str) -> Result<String> {
@@ -116,91 +877,440 @@ async fn fetch_mcp_api_key(project_id: &str) -> Result<String> {
         .await
         .context("Failed to list API keys")?;
 
-    let keys = response.1.keys.context("No keys found in project")?;
-    
-    let target_key = keys
-        .into_iter()
-        .find(|k| k.display_name.as_deref() == Some("MCP API Key"))
-        .context("MCP API Key not found")?;
+    let keys = response.1.keys.context("No keys found in project")?;
+    
+    let target_key = keys
+        .into_iter()
+        .find(|k| k.display_name.as_deref() == Some("MCP API Key"))
+        .context("MCP API Key not found")?;
+
+    let key_name = target_key.name.context("Key has no name")?;
+    tracing::info!("Found MCP API Key resource: {}", key_name);
+
+    // 4. Get the key string (the secret)
+    let response = hub
+        .projects()
+        .locations_keys_get_key_string(&key_name)
+        .doit()
+        .await
+        .context("Failed to get key string")?;
+
+    let key_string = response.1.key_string.context("Response contained no key string")?;
+
+    Ok(key_string)
+}
+
+/// Lists every API key in the project and resolves its secret, building a
+/// registry keyed by the secret itself so `iap_middleware` can do an O(1)
+/// lookup on the presented `x-goog-api-key`. Each key's scope/expiry is
+/// encoded in its Cloud `display_name` (see `parse_key_entry`).
+async fn fetch_key_registry(project_id: &str) -> Result<HashMap<String, ApiKeyEntry>> {
+    let client = hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
+        .build(
+            hyper_rustls::HttpsConnectorBuilder::new()
+                .with_native_roots()
+                .unwrap()
+                .https_or_http()
+                .enable_http1()
+                .build(),
+        );
+
+    let opts = yup_oauth2::ApplicationDefaultCredentialsFlowOpts::default();
+    let auth_builder = ApplicationDefaultCredentialsAuthenticator::builder(opts).await;
+
+    let auth: yup_oauth2::authenticator::Authenticator<_> = match auth_builder {
+        ApplicationDefaultCredentialsTypes::InstanceMetadata(builder) => builder
+            .build()
+            .await
+            .context("Failed to build InstanceMetadata authenticator")?,
+        ApplicationDefaultCredentialsTypes::ServiceAccount(builder) => builder
+            .build()
+            .await
+            .context("Failed to build ServiceAccount authenticator")?,
+    };
+
+    let hub = ApiKeysService::new(client, auth);
+    let parent = format!("projects/{}/locations/global", project_id);
+
+    let response = hub
+        .projects()
+        .locations_keys_list(&parent)
+        .doit()
+        .await
+        .context("Failed to list API keys")?;
+
+    let keys = response.1.keys.unwrap_or_default();
+    let mut registry = HashMap::new();
+
+    for key in keys {
+        let (Some(key_name), Some(display_name)) = (key.name, key.display_name) else {
+            continue;
+        };
+
+        match hub
+            .projects()
+            .locations_keys_get_key_string(&key_name)
+            .doit()
+            .await
+        {
+            Ok(response) => {
+                if let Some(key_string) = response.1.key_string {
+                    registry.insert(key_string, parse_key_entry(&display_name));
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Failed to get key string for {}: {:?}", key_name, e);
+            }
+        }
+    }
+
+    Ok(registry)
+}
+
+fn collect_system_info() -> String {
+    let mut sys = System::new_all();
+    sys.refresh_all();
+
+    let mut report = String::new();
+
+    report.push_str("System Information Report\n");
+    report.push_str("=========================\n\n");
+
+    // System name and kernel
+    report.push_str("System Information\n");
+    report.push_str("------------------\n");
+    report.push_str(&format!(
+        "System Name:      {}\n",
+        System::name().unwrap_or_else(|| "<unknown>".to_string())
+    ));
+    report.push_str(&format!(
+        "Kernel Version:   {}\n",
+        System::kernel_version().unwrap_or_else(|| "<unknown>".to_string())
+    ));
+    report.push_str(&format!(
+        "OS Version:       {}\n",
+        System::os_version().unwrap_or_else(|| "<unknown>".to_string())
+    ));
+    report.push_str(&format!(
+        "Host Name:        {}\n",
+        System::host_name().unwrap_or_else(|| "<unknown>".to_string())
+    ));
+
+    report.push_str("\nCPU Information\n");
+    report.push_str("---------------\n");
+    report.push_str(&format!("Number of Cores:  {}\n", sys.cpus().len()));
+
+    report.push_str("\nMemory Information\n");
+    report.push_str("------------------\n");
+    report.push_str(&format!(
+        "Total Memory:     {} MB\n",
+        sys.total_memory() / 1024 / 1024
+    ));
+    report.push_str(&format!(
+        "Used Memory:      {} MB\n",
+        sys.used_memory() / 1024 / 1024
+    ));
+    report.push_str(&format!(
+        "Total Swap:       {} MB\n",
+        sys.total_swap() / 1024 / 1024
+    ));
+    report.push_str(&format!(
+        "Used Swap:        {} MB\n",
+        sys.used_swap() / 1024 / 1024
+    ));
+
+    report.push_str("\nNetwork Interfaces\n");
+    report.push_str("------------------\n");
+    let networks = sysinfo::Networks::new_with_refreshed_list();
+    for (interface_name, network) in &networks {
+        report.push_str(&format!(
+            "{:<18}: RX: {:>10} bytes, TX: {:>10} bytes (MAC: {})\n",
+            interface_name,
+            network.total_received(),
+            network.total_transmitted(),
+            network.mac_address()
+        ));
+    }
+
+    report
+}
+
+fn collect_disk_usage() -> String {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+
+    let mut report = String::new();
+    report.push_str("Disk Usage Report\n");
+    report.push_str("=================\n\n");
+
+    for disk in &disks {
+        let total = disk.total_space();
+        let available = disk.available_space();
+        let used = total - available;
+        let usage_pct = if total > 0 {
+            (used as f64 / total as f64) * 100.0
+        } else {
+            0.0
+        };
+
+        report.push_str(&format!(
+            "{:<20} {:<10} {:>10} / {:>10} MB used ({:.1}%)\n",
+            disk.mount_point().to_string_lossy(),
+            disk.file_system().to_string_lossy(),
+            used / 1024 / 1024,
+            total / 1024 / 1024,
+            usage_pct
+        ));
+    }
+
+    report
+}
+
+fn collect_process_list() -> String {
+    let mut sys = System::new_all();
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+
+    let mut report = String::new();
+    report.push_str("Process List Report\n");
+    report.push_str("===================\n\n");
+    report.push_str(&format!(
+        "{:<10} {:<20} {:>12}\n",
+        "PID", "Name", "Memory (KB)"
+    ));
+    report.push_str("------------------------------------------\n");
+
+    let mut processes: Vec<_> = sys.processes().values().collect();
+    processes.sort_by_key(|p| p.memory());
+    processes.reverse();
+
+    // Show top 20 processes by memory usage
+    for process in processes.iter().take(20) {
+        report.push_str(&format!(
+            "{:<10} {:<20} {:>12}\n",
+            process.pid().to_string(),
+            process.name().to_string_lossy(),
+            process.memory() / 1024
+        ));
+    }
+
+    report
+}
+
+struct ConnectionInfo {
+    protocol: &'static str,
+    local_addr: String,
+    remote_addr: String,
+    state: String,
+    inode: u64,
+}
+
+fn tcp_state_name(code: &str) -> &'static str {
+    match code {
+        "01" => "ESTABLISHED",
+        "02" => "SYN_SENT",
+        "03" => "SYN_RECV",
+        "04" => "FIN_WAIT1",
+        "05" => "FIN_WAIT2",
+        "06" => "TIME_WAIT",
+        "07" => "CLOSE",
+        "08" => "CLOSE_WAIT",
+        "09" => "LAST_ACK",
+        "0A" => "LISTEN",
+        "0B" => "CLOSING",
+        _ => "UNKNOWN",
+    }
+}
+
+/// Decodes a `/proc/net/{tcp,udp}` address field (`<hex-ip>:<hex-port>`,
+/// little-endian bytes) into a human-readable `ip:port` string. Handles both
+/// the 8-hex-char IPv4 form (`tcp`/`udp`) and the 32-hex-char IPv6 form
+/// (`tcp6`/`udp6`), where the 16 address bytes are laid out as four
+/// little-endian 32-bit words.
+fn parse_proc_net_addr(field: &str) -> Option<String> {
+    let (ip_hex, port_hex) = field.split_once(':')?;
+    let port = u16::from_str_radix(port_hex, 16).ok()?;
+
+    match ip_hex.len() {
+        8 => {
+            let b = u32::from_str_radix(ip_hex, 16).ok()?.to_le_bytes();
+            Some(format!("{}.{}.{}.{}:{}", b[0], b[1], b[2], b[3], port))
+        }
+        32 => {
+            let mut bytes = [0u8; 16];
+            for (i, word_hex) in ip_hex.as_bytes().chunks(8).enumerate() {
+                let word = u32::from_str_radix(std::str::from_utf8(word_hex).ok()?, 16).ok()?;
+                bytes[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+            }
+            Some(format!("[{}]:{}", std::net::Ipv6Addr::from(bytes), port))
+        }
+        _ => None,
+    }
+}
+
+fn parse_proc_net_line(line: &str, protocol: &'static str) -> Option<ConnectionInfo> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() < 10 {
+        return None;
+    }
+
+    let local_addr = parse_proc_net_addr(fields[1])?;
+    let remote_addr = parse_proc_net_addr(fields[2])?;
+    let inode: u64 = fields[9].parse().ok()?;
+    let state = if protocol.starts_with("udp") {
+        String::new()
+    } else {
+        tcp_state_name(fields[3]).to_string()
+    };
+
+    Some(ConnectionInfo {
+        protocol,
+        local_addr,
+        remote_addr,
+        state,
+        inode,
+    })
+}
+
+/// Walks `/proc/<pid>/fd` looking for `socket:[inode]` symlinks so each
+/// connection can be attributed to its owning process.
+fn build_inode_to_process_map() -> HashMap<u64, (u32, String)> {
+    let mut map = HashMap::new();
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return map;
+    };
+
+    for entry in entries.flatten() {
+        let Ok(pid) = entry.file_name().to_string_lossy().parse::<u32>() else {
+            continue;
+        };
+        let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+        let name = std::fs::read_to_string(entry.path().join("comm"))
+            .map(|s| s.trim().to_string())
+            .unwrap_or_else(|_| "<unknown>".to_string());
+
+        for fd in fds.flatten() {
+            let Ok(link) = std::fs::read_link(fd.path()) else {
+                continue;
+            };
+            let link_str = link.to_string_lossy();
+            if let Some(inode) = link_str
+                .strip_prefix("socket:[")
+                .and_then(|s| s.strip_suffix(']'))
+                .and_then(|s| s.parse::<u64>().ok())
+            {
+                map.insert(inode, (pid, name.clone()));
+            }
+        }
+    }
+
+    map
+}
+
+fn collect_network_connections() -> String {
+    let mut connections = Vec::new();
+    for (path, protocol) in [
+        ("/proc/net/tcp", "tcp"),
+        ("/proc/net/tcp6", "tcp6"),
+        ("/proc/net/udp", "udp"),
+        ("/proc/net/udp6", "udp6"),
+    ] {
+        if let Ok(content) = std::fs::read_to_string(path) {
+            connections.extend(content.lines().skip(1).filter_map(|line| parse_proc_net_line(line, protocol)));
+        }
+    }
+    connections.sort_by(|a, b| a.local_addr.cmp(&b.local_addr));
 
-    let key_name = target_key.name.context("Key has no name")?;
-    tracing::info!("Found MCP API Key resource: {}", key_name);
+    let inode_to_process = build_inode_to_process_map();
 
-    // 4. Get the key string (the secret)
-    let response = hub
-        .projects()
-        .locations_keys_get_key_string(&key_name)
-        .doit()
-        .await
-        .context("Failed to get key string")?;
+    let mut report = String::new();
+    report.push_str("Network Connections Report\n");
+    report.push_str("===========================\n\n");
+    report.push_str(&format!(
+        "{:<6} {:<22} {:<22} {:<12} {:<8} {:<20}\n",
+        "Proto", "Local Address", "Remote Address", "State", "PID", "Process"
+    ));
+    report.push_str(&"-".repeat(95));
+    report.push('\n');
+
+    for conn in &connections {
+        let (pid, name) = inode_to_process
+            .get(&conn.inode)
+            .cloned()
+            .unwrap_or((0, "-".to_string()));
+        report.push_str(&format!(
+            "{:<6} {:<22} {:<22} {:<12} {:<8} {:<20}\n",
+            conn.protocol,
+            conn.local_addr,
+            conn.remote_addr,
+            if conn.state.is_empty() { "-" } else { conn.state.as_str() },
+            if pid == 0 { "-".to_string() } else { pid.to_string() },
+            name
+        ));
+    }
 
-    let key_string = response.1.key_string.context("Response contained no key string")?;
-    
-    Ok(key_string)
+    report
 }
 
-fn collect_system_info() -> String {
+/// Minimum interval `sysinfo` needs between CPU refreshes before
+/// `cpu_usage()` reflects real load rather than 0%.
+const CPU_SAMPLE_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+
+/// Samples overall and per-process CPU usage over a short window, then
+/// reports the top-N processes by CPU or memory, like a one-shot `top`.
+async fn collect_system_monitor(top_n: usize, sort_by: &str) -> String {
     let mut sys = System::new_all();
-    sys.refresh_all();
+    sys.refresh_cpu_all();
+    tokio::time::sleep(CPU_SAMPLE_INTERVAL).await;
+    sys.refresh_cpu_all();
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
 
     let mut report = String::new();
+    report.push_str("System Monitor Report\n");
+    report.push_str("======================\n\n");
 
-    report.push_str("System Information Report\n");
-    report.push_str("=========================\n\n");
-
-    // System name and kernel
-    report.push_str("System Information\n");
-    report.push_str("------------------\n");
-    report.push_str(&format!(
-        "System Name:      {}\n",
-        System::name().unwrap_or_else(|| "<unknown>".to_string())
-    ));
-    report.push_str(&format!(
-        "Kernel Version:   {}\n",
-        System::kernel_version().unwrap_or_else(|| "<unknown>".to_string())
-    ));
-    report.push_str(&format!(
-        "OS Version:       {}\n",
-        System::os_version().unwrap_or_else(|| "<unknown>".to_string())
-    ));
-    report.push_str(&format!(
-        "Host Name:        {}\n",
-        System::host_name().unwrap_or_else(|| "<unknown>".to_string())
-    ));
+    let global_usage: f32 =
+        sys.cpus().iter().map(|cpu| cpu.cpu_usage()).sum::<f32>() / sys.cpus().len().max(1) as f32;
+    report.push_str(&format!("Overall CPU Load:  {:.1}%\n\n", global_usage));
 
-    report.push_str("\nCPU Information\n");
-    report.push_str("---------------\n");
-    report.push_str(&format!("Number of Cores:  {}\n", sys.cpus().len()));
+    report.push_str("Per-Core Usage\n");
+    report.push_str("--------------\n");
+    for (i, cpu) in sys.cpus().iter().enumerate() {
+        report.push_str(&format!("Core {:<3}:         {:.1}%\n", i, cpu.cpu_usage()));
+    }
+    report.push('\n');
 
-    report.push_str("\nMemory Information\n");
-    report.push_str("------------------\n");
-    report.push_str(&format!(
-        "Total Memory:     {} MB\n",
-        sys.total_memory() / 1024 / 1024
-    ));
-    report.push_str(&format!(
-        "Used Memory:      {} MB\n",
-        sys.used_memory() / 1024 / 1024
-    ));
+    let load = System::load_average();
     report.push_str(&format!(
-        "Total Swap:       {} MB\n",
-        sys.total_swap() / 1024 / 1024
+        "Load Average:      {:.2} (1m)  {:.2} (5m)  {:.2} (15m)\n\n",
+        load.one, load.five, load.fifteen
     ));
+
+    report.push_str(&format!("Top {} Processes by {}\n", top_n, sort_by));
+    report.push_str("------------------------------------------\n");
     report.push_str(&format!(
-        "Used Swap:        {} MB\n",
-        sys.used_swap() / 1024 / 1024
+        "{:<10} {:<20} {:>8} {:>12}\n",
+        "PID", "Name", "CPU%", "RSS (KB)"
     ));
 
-    report.push_str("\nNetwork Interfaces\n");
-    report.push_str("------------------\n");
-    let networks = sysinfo::Networks::new_with_refreshed_list();
-    for (interface_name, network) in &networks {
+    let mut processes: Vec<_> = sys.processes().values().collect();
+    if sort_by.eq_ignore_ascii_case("memory") {
+        processes.sort_by(|a, b| b.memory().cmp(&a.memory()));
+    } else {
+        processes.sort_by(|a, b| {
+            b.cpu_usage()
+                .partial_cmp(&a.cpu_usage())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    for process in processes.iter().take(top_n) {
         report.push_str(&format!(
-            "{:<18}: RX: {:>10} bytes, TX: {:>10} bytes (MAC: {})\n",
-            interface_name,
-            network.total_received(),
-            network.total_transmitted(),
-            network.mac_address()
+            "{:<10} {:<20} {:>8.1} {:>12}\n",
+            process.pid().to_string(),
+            process.name().to_string_lossy(),
+            process.cpu_usage(),
+            process.memory() / 1024
         ));
     }
 
@@ -212,83 +1322,258 @@ impl SysUtils {
     fn new() -> Self {
         Self {
             tool_router: Self::tool_router(),
+            children: CHILD_PROCESSES.clone(),
         }
     }
 
     #[tool(
-        description = "Get a detailed system information report including kernel, cores, and memory usage.",
+        description = "Get a detailed system information report including kernel, cores, and memory usage. Set format: \"json\" for a machine-readable result.",
         input_schema = "SYSTEM_INFO_SCHEMA.clone()"
     )]
-    async fn sysutils_manual_rust(&self, _params: Parameters<IapSystemInfoRequest>) -> String {
-        collect_system_info()
+    async fn sysutils_manual_rust(&self, Parameters(req): Parameters<IapSystemInfoRequest>) -> String {
+        if let Ok(Some(scope)) = ALLOWED_TOOLS.try_with(|s| s.clone()) {
+            if !scope.is_empty() && !scope.contains("sysutils_manual_rust") {
+                return "Error: the presented API key is not scoped for the 'sysutils_manual_rust' tool.".to_string();
+            }
+        }
+
+        match req.format {
+            OutputFormat::Text => collect_system_info(),
+            OutputFormat::Json => serde_json::to_string_pretty(&gather_system_info_report())
+                .unwrap_or_else(|e| format!("Error serializing system report: {:?}", e)),
+        }
     }
 
     #[tool(
-        description = "Get disk usage information for all mounted disks.",
+        description = "Get disk usage information for all mounted disks. Set format: \"json\" for a machine-readable result.",
         input_schema = "DISK_USAGE_SCHEMA.clone()"
     )]
-    async fn disk_usage(&self, _params: Parameters<DiskUsageRequest>) -> String {
-        let disks = sysinfo::Disks::new_with_refreshed_list();
+    async fn disk_usage(&self, Parameters(req): Parameters<DiskUsageRequest>) -> String {
+        if let Ok(Some(scope)) = ALLOWED_TOOLS.try_with(|s| s.clone()) {
+            if !scope.is_empty() && !scope.contains("disk_usage") {
+                return "Error: the presented API key is not scoped for the 'disk_usage' tool.".to_string();
+            }
+        }
 
-        let mut report = String::new();
-        report.push_str("Disk Usage Report\n");
-        report.push_str("=================\n\n");
+        match req.format {
+            OutputFormat::Text => collect_disk_usage(),
+            OutputFormat::Json => serde_json::to_string_pretty(&gather_disk_report())
+                .unwrap_or_else(|e| format!("Error serializing disk report: {:?}", e)),
+        }
+    }
 
-        for disk in &disks {
-            let total = disk.total_space();
-            let available = disk.available_space();
-            let used = total - available;
-            let usage_pct = if total > 0 {
-                (used as f64 / total as f64) * 100.0
-            } else {
-                0.0
-            };
+    #[tool(
+        description = "List all running processes and their memory usage. Set format: \"json\" for a machine-readable result.",
+        input_schema = "PROCESS_LIST_SCHEMA.clone()"
+    )]
+    async fn list_processes(&self, Parameters(req): Parameters<ProcessListRequest>) -> String {
+        if let Ok(Some(scope)) = ALLOWED_TOOLS.try_with(|s| s.clone()) {
+            if !scope.is_empty() && !scope.contains("list_processes") {
+                return "Error: the presented API key is not scoped for the 'list_processes' tool.".to_string();
+            }
+        }
 
-            report.push_str(&format!(
-                "{:<20} {:<10} {:>10} / {:>10} MB used ({:.1}%)\n",
-                disk.mount_point().to_string_lossy(),
-                disk.file_system().to_string_lossy(),
-                used / 1024 / 1024,
-                total / 1024 / 1024,
-                usage_pct
-            ));
+        match req.format {
+            OutputFormat::Text => collect_process_list(),
+            OutputFormat::Json => serde_json::to_string_pretty(&gather_process_report())
+                .unwrap_or_else(|e| format!("Error serializing process report: {:?}", e)),
+        }
+    }
+
+    #[tool(
+        description = "List active TCP/UDP connections and listening sockets, with owning PID/process name where available.",
+        input_schema = "NETWORK_CONNECTIONS_SCHEMA.clone()"
+    )]
+    async fn network_connections(&self, _params: Parameters<NetworkConnectionsRequest>) -> String {
+        if let Ok(Some(scope)) = ALLOWED_TOOLS.try_with(|s| s.clone()) {
+            if !scope.is_empty() && !scope.contains("network_connections") {
+                return "Error: the presented API key is not scoped for the 'network_connections' tool.".to_string();
+            }
         }
 
-        report
+        collect_network_connections()
     }
 
     #[tool(
-        description = "List all running processes and their memory usage.",
-        input_schema = "PROCESS_LIST_SCHEMA.clone()"
+        description = "Sample CPU load and report the top-N processes by CPU or memory usage, like a one-shot `top`.",
+        input_schema = "SYSTEM_MONITOR_SCHEMA.clone()"
     )]
-    async fn list_processes(&self, _params: Parameters<ProcessListRequest>) -> String {
-        let mut sys = System::new_all();
-        sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+    async fn system_monitor(&self, Parameters(req): Parameters<SystemMonitorRequest>) -> String {
+        if let Ok(Some(scope)) = ALLOWED_TOOLS.try_with(|s| s.clone()) {
+            if !scope.is_empty() && !scope.contains("system_monitor") {
+                return "Error: the presented API key is not scoped for the 'system_monitor' tool.".to_string();
+            }
+        }
 
-        let mut report = String::new();
-        report.push_str("Process List Report\n");
-        report.push_str("===================\n\n");
-        report.push_str(&format!(
-            "{:<10} {:<20} {:>12}\n",
-            "PID", "Name", "Memory (KB)"
-        ));
-        report.push_str("------------------------------------------\n");
+        let top_n = req.top_n.unwrap_or(10).clamp(1, 100);
+        let sort_by = req.sort_by.as_deref().unwrap_or("cpu").to_string();
+        collect_system_monitor(top_n, &sort_by).await
+    }
+
+    #[tool(
+        description = "Send a signal (default 15/SIGTERM) to a PID. Refuses PID 1 and the server's own process, and only sends signals in the configured allowlist.",
+        input_schema = "SIGNAL_PROCESS_SCHEMA.clone()"
+    )]
+    async fn signal_process(&self, Parameters(req): Parameters<SignalProcessRequest>) -> String {
+        if let Ok(Some(scope)) = ALLOWED_TOOLS.try_with(|s| s.clone()) {
+            if !scope.is_empty() && !scope.contains("signal_process") {
+                return "Error: the presented API key is not scoped for the 'signal_process' tool.".to_string();
+            }
+        }
+
+        let signal = req.signal.unwrap_or(15);
+        let identity = iap_identity_summary();
+        match send_signal(req.pid, signal).await {
+            Ok(()) => {
+                tracing::info!("{} sent signal {} to PID {}", identity, signal, req.pid);
+                format!("Sent signal {} to PID {}", signal, req.pid)
+            }
+            Err(e) => {
+                tracing::warn!("{} failed to signal PID {}: {:?}", identity, req.pid, e);
+                format!("Error: {:?}", e)
+            }
+        }
+    }
+
+    #[tool(
+        description = "Launch a command, capturing stdout/stderr up to a size cap and waiting up to a timeout for it to finish. Still-running processes can be checked later with process_status/process_wait.",
+        input_schema = "SPAWN_PROCESS_SCHEMA.clone()"
+    )]
+    async fn spawn_process(&self, Parameters(req): Parameters<SpawnProcessRequest>) -> String {
+        if let Ok(Some(scope)) = ALLOWED_TOOLS.try_with(|s| s.clone()) {
+            if !scope.is_empty() && !scope.contains("spawn_process") {
+                return "Error: the presented API key is not scoped for the 'spawn_process' tool.".to_string();
+            }
+        }
+
+        let timeout_secs = req
+            .timeout_secs
+            .unwrap_or(SPAWN_DEFAULT_TIMEOUT_SECS)
+            .clamp(1, SPAWN_MAX_TIMEOUT_SECS);
+        let max_output_bytes = req
+            .max_output_bytes
+            .unwrap_or(SPAWN_DEFAULT_MAX_OUTPUT_BYTES)
+            .clamp(1, SPAWN_MAX_MAX_OUTPUT_BYTES);
+
+        let mut command = tokio::process::Command::new(&req.command);
+        command
+            .args(&req.args)
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped());
+
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(e) => return format!("Error spawning '{}': {:?}", req.command, e),
+        };
+
+        let id = Uuid::new_v4();
+        let pid = child.id();
+        let stdout_buf = Arc::new(StdMutex::new(Vec::new()));
+        let stdout_truncated = Arc::new(AtomicBool::new(false));
+        let stderr_buf = Arc::new(StdMutex::new(Vec::new()));
+        let stderr_truncated = Arc::new(AtomicBool::new(false));
+        let exit_code = Arc::new(StdMutex::new(None));
+        let done = Arc::new(tokio::sync::Notify::new());
+
+        if let Some(stdout) = child.stdout.take() {
+            spawn_output_reader(stdout, stdout_buf.clone(), stdout_truncated.clone(), max_output_bytes);
+        }
+        if let Some(stderr) = child.stderr.take() {
+            spawn_output_reader(stderr, stderr_buf.clone(), stderr_truncated.clone(), max_output_bytes);
+        }
+
+        let started_by = iap_identity_summary();
+        tracing::info!(
+            "{} spawned '{}' {:?} as {} (pid {:?})",
+            started_by, req.command, req.args, id, pid
+        );
+
+        {
+            let exit_code = exit_code.clone();
+            let done = done.clone();
+            tokio::spawn(async move {
+                if let Ok(status) = child.wait().await {
+                    *exit_code.lock().unwrap() = Some(status.code().unwrap_or(-1));
+                }
+                done.notify_waiters();
+            });
+        }
+
+        let handle = ChildHandle {
+            command: req.command.clone(),
+            args: req.args.clone(),
+            pid,
+            started_at: Instant::now(),
+            started_by,
+            stdout: stdout_buf,
+            stdout_truncated,
+            stderr: stderr_buf,
+            stderr_truncated,
+            exit_code,
+            done,
+        };
+        self.children.lock().await.insert(id, handle);
+
+        match wait_for_child(&self.children, id, Duration::from_secs(timeout_secs)).await {
+            Some(report) => serde_json::to_string_pretty(&report)
+                .unwrap_or_else(|e| format!("Error serializing process report: {:?}", e)),
+            None => "Error: spawned process disappeared from the registry".to_string(),
+        }
+    }
+
+    #[tool(
+        description = "Check on a process started by spawn_process without waiting for it to finish.",
+        input_schema = "PROCESS_STATUS_SCHEMA.clone()"
+    )]
+    async fn process_status(&self, Parameters(req): Parameters<ProcessStatusRequest>) -> String {
+        if let Ok(Some(scope)) = ALLOWED_TOOLS.try_with(|s| s.clone()) {
+            if !scope.is_empty() && !scope.contains("process_status") {
+                return "Error: the presented API key is not scoped for the 'process_status' tool.".to_string();
+            }
+        }
+
+        let Ok(id) = Uuid::parse_str(&req.id) else {
+            return format!("Error: '{}' is not a valid process id", req.id);
+        };
+
+        let guard = self.children.lock().await;
+        match guard.get(&id) {
+            Some(handle) => serde_json::to_string_pretty(&handle.to_report(id))
+                .unwrap_or_else(|e| format!("Error serializing process report: {:?}", e)),
+            None => format!("Error: no spawned process with id {}", id),
+        }
+    }
+
+    #[tool(
+        description = "Wait (bounded by a timeout) for a process started by spawn_process to finish, reaping it from the registry once it has.",
+        input_schema = "PROCESS_WAIT_SCHEMA.clone()"
+    )]
+    async fn process_wait(&self, Parameters(req): Parameters<ProcessWaitRequest>) -> String {
+        if let Ok(Some(scope)) = ALLOWED_TOOLS.try_with(|s| s.clone()) {
+            if !scope.is_empty() && !scope.contains("process_wait") {
+                return "Error: the presented API key is not scoped for the 'process_wait' tool.".to_string();
+            }
+        }
 
-        let mut processes: Vec<_> = sys.processes().values().collect();
-        processes.sort_by_key(|p| p.memory());
-        processes.reverse();
+        let Ok(id) = Uuid::parse_str(&req.id) else {
+            return format!("Error: '{}' is not a valid process id", req.id);
+        };
 
-        // Show top 20 processes by memory usage
-        for process in processes.iter().take(20) {
-            report.push_str(&format!(
-                "{:<10} {:<20} {:>12}\n",
-                process.pid().to_string(),
-                process.name().to_string_lossy(),
-                process.memory() / 1024
-            ));
+        let timeout_secs = req
+            .timeout_secs
+            .unwrap_or(SPAWN_DEFAULT_TIMEOUT_SECS)
+            .clamp(1, SPAWN_MAX_TIMEOUT_SECS);
+
+        let Some(report) = wait_for_child(&self.children, id, Duration::from_secs(timeout_secs)).await else {
+            return format!("Error: no spawned process with id {}", id);
+        };
+
+        if !report.running {
+            self.children.lock().await.remove(&id);
         }
 
-        report
+        serde_json::to_string_pretty(&report)
+            .unwrap_or_else(|e| format!("Error serializing process report: {:?}", e))
     }
 }
 
@@ -306,31 +1591,138 @@ impl ServerHandler for SysUtils {
 }
 
 async fn iap_middleware(
-    Extension(expected_key): Extension<ApiKey>,
+    Extension(registry): Extension<KeyRegistry>,
     req: Request<Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    // 1. Capture IAP JWT (optional but good for logging/context)
+    // Skip health/observability endpoints: /stats exists precisely so
+    // operators can scrape it without speaking the MCP protocol (or, by
+    // extension, without an IAP/API key in front of it either).
+    let path = req.uri().path().to_string();
+    if path == "/health" || path == "/stats" {
+        return Ok(next.run(req).await);
+    }
+
+    let headers: Vec<(String, String)> = req
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                value.to_str().unwrap_or("<non-utf8>").to_string(),
+            )
+        })
+        .collect();
+
+    // 1. Verify (or, with IAP_VERIFY_JWT=0, merely decode) the IAP JWT so
+    // tool handlers can trust the identity in IAP_CONTEXT instead of a raw,
+    // unauthenticated header that any client could forge.
+    let mut iap_context = None;
     if let Some(jwt) = req.headers().get("x-goog-iap-jwt-assertion") {
-        if let Ok(jwt_str) = jwt.to_str() {
-            tracing::debug!("IAP JWT found: {}", jwt_str);
+        let Ok(jwt_str) = jwt.to_str() else {
+            tracing::error!("x-goog-iap-jwt-assertion header contains non-UTF8 data");
+            return Err(StatusCode::UNAUTHORIZED);
+        };
+
+        if iap_verify_enabled() {
+            match verify_iap_jwt(jwt_str).await {
+                Some(ctx) => {
+                    tracing::info!("IAP JWT verified successfully. Claims: {}", ctx.payload);
+                    iap_context = Some(ctx);
+                }
+                None => {
+                    tracing::warn!("IAP JWT failed signature/claim verification");
+                    return Err(StatusCode::UNAUTHORIZED);
+                }
+            }
+        } else if let Some(ctx) = decode_iap_jwt(jwt_str) {
+            tracing::info!("IAP JWT decoded (verification disabled). Claims: {}", ctx.payload);
+            iap_context = Some(ctx);
+        } else {
+            tracing::error!("Failed to decode x-goog-iap-jwt-assertion payload");
         }
+    } else {
+        tracing::debug!("No x-goog-iap-jwt-assertion header found");
     }
 
-    // 2. Validate API Key if set
-    if let Some(expected_key) = expected_key.0.as_ref() {
-        let provided_key = req
-            .headers()
-            .get("x-goog-api-key")
-            .and_then(|h| h.to_str().ok());
+    // 2. Validate the presented API key against the registry, when configured
+    if registry.0.is_empty() {
+        return Ok(REQUEST_HEADERS
+            .scope(
+                headers,
+                IAP_CONTEXT.scope(iap_context, ALLOWED_TOOLS.scope(None, next.run(req))),
+            )
+            .await);
+    }
 
-        if provided_key != Some(expected_key) {
-            tracing::warn!("Unauthorized: Invalid or missing x-goog-api-key");
-            return Err(StatusCode::UNAUTHORIZED);
-        }
+    let provided_key = req
+        .headers()
+        .get("x-goog-api-key")
+        .and_then(|h| h.to_str().ok());
+
+    let Some(entry) = provided_key.and_then(|k| registry.0.get(k)) else {
+        tracing::warn!("Unauthorized: unknown or missing x-goog-api-key");
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    if entry.is_expired() {
+        tracing::warn!("Unauthorized: API key '{}' has expired", entry.display_name);
+        return Err(StatusCode::UNAUTHORIZED);
     }
 
-    Ok(next.run(req).await)
+    let allowed_tools = entry.allowed_tools.clone();
+    Ok(REQUEST_HEADERS
+        .scope(
+            headers,
+            IAP_CONTEXT.scope(
+                iap_context,
+                ALLOWED_TOOLS.scope(Some(allowed_tools), next.run(req)),
+            ),
+        )
+        .await)
+}
+
+/// JSON body for `GET /stats`: the same structured system snapshot the
+/// `sysutils_manual_rust` tool returns in `format: "json"` mode, plus a quick process/memory
+/// summary, so operators can scrape it without speaking the MCP protocol.
+#[derive(Debug, serde::Serialize)]
+struct StatsResponse {
+    #[serde(flatten)]
+    system: SystemInfoReport,
+    process_count: usize,
+    top_memory_process: Option<ProcessEntry>,
+}
+
+async fn stats_handler() -> axum::Json<StatsResponse> {
+    let system = gather_system_info_report();
+
+    let mut sys = System::new_all();
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+    let process_count = sys.processes().len();
+    let top_memory_process = sys
+        .processes()
+        .values()
+        .max_by_key(|p| p.memory())
+        .map(|p| ProcessEntry {
+            pid: p.pid().as_u32(),
+            name: p.name().to_string_lossy().to_string(),
+            memory_kb: p.memory() / 1024,
+        });
+
+    axum::Json(StatsResponse {
+        system,
+        process_count,
+        top_memory_process,
+    })
+}
+
+fn find_flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    for i in 1..args.len() {
+        if args[i] == flag && i + 1 < args.len() {
+            return Some(&args[i + 1]);
+        }
+    }
+    None
 }
 
 #[tokio::main]
@@ -338,21 +1730,56 @@ async fn main() -> Result<()> {
     // Check for CLI arguments
     let args: Vec<String> = std::env::args().collect();
     if args.len() > 1 {
+        let format = if find_flag_value(&args, "--format") == Some("json") {
+            OutputFormat::Json
+        } else {
+            OutputFormat::Text
+        };
+
         if args[1] == "info" {
-            println!("{}", collect_system_info());
+            let sysutils = SysUtils::new();
+            println!(
+                "{}",
+                sysutils
+                    .sysutils_manual_rust(Parameters(IapSystemInfoRequest { format }))
+                    .await
+            );
             return Ok(());
         } else if args[1] == "disk" {
             let sysutils = SysUtils::new();
             println!(
                 "{}",
-                sysutils.disk_usage(Parameters(DiskUsageRequest {})).await
+                sysutils.disk_usage(Parameters(DiskUsageRequest { format })).await
             );
             return Ok(());
         } else if args[1] == "processes" {
             let sysutils = SysUtils::new();
             println!(
                 "{}",
-                sysutils.list_processes(Parameters(ProcessListRequest {})).await
+                sysutils
+                    .list_processes(Parameters(ProcessListRequest { format }))
+                    .await
+            );
+            return Ok(());
+        } else if args[1] == "net" {
+            let sysutils = SysUtils::new();
+            println!(
+                "{}",
+                sysutils
+                    .network_connections(Parameters(NetworkConnectionsRequest {}))
+                    .await
+            );
+            return Ok(());
+        } else if args[1] == "monitor" {
+            let sysutils = SysUtils::new();
+            println!(
+                "{}",
+                sysutils
+                    .system_monitor(Parameters(SystemMonitorRequest {
+                        top_n: None,
+                        sort_by: None,
+                    }))
+                    .await
             );
             return Ok(());
         }
@@ -371,23 +1798,20 @@ async fn main() -> Result<()> {
         )
         .init();
 
-    // Fetch MCP API Key
+    // Build the API key registry from Cloud API Keys, then layer on any
+    // local-testing overrides from MCP_API_KEYS_JSON.
     // Hardcoded project ID for demonstration; in production this should be from env or metadata
     let project_id = "1056842563084";
-    let fetched_key = match fetch_mcp_api_key(project_id).await {
-        Ok(key) => {
-            tracing::info!("Successfully fetched MCP API Key from Cloud API Keys");
-            Some(key)
-        }
+    let mut registry = match fetch_key_registry(project_id).await {
+        Ok(registry) => registry,
         Err(e) => {
-            tracing::error!("Failed to fetch MCP API Key: {:?}", e);
-            None
+            tracing::error!("Failed to fetch API key registry from Cloud: {:?}", e);
+            HashMap::new()
         }
     };
-
-    // Prefer environment variable if set, otherwise use fetched key
-    let mcp_api_key = std::env::var("MCP_API_KEY").ok().or(fetched_key);
-    let api_key_state = ApiKey(Arc::new(mcp_api_key));
+    load_env_key_overrides(&mut registry);
+    tracing::info!("Loaded {} API key(s) into the registry", registry.len());
+    let key_registry = KeyRegistry(Arc::new(registry));
 
     let service_factory = || Ok(SysUtils::new());
     let session_manager = LocalSessionManager::default();
@@ -399,8 +1823,9 @@ async fn main() -> Result<()> {
     let app = axum::Router::new()
         .fallback_service(service)
         .route("/health", axum::routing::get(|| async { "ok" }))
+        .route("/stats", axum::routing::get(stats_handler))
         .layer(middleware::from_fn(iap_middleware))
-        .layer(Extension(api_key_state));
+        .layer(Extension(key_registry));
 
     // Determine port from environment variable (Cloud Run standard)
     let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
@@ -444,6 +1869,7 @@ async fn shutdown_signal() {
     }
 
     tracing::info!("Signal received, starting graceful shutdown...");
+    reap_child_processes().await;
 }
 
 #[cfg(test)]
@@ -458,11 +1884,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_key_entry() {
+        let entry = parse_key_entry("name=dashboard;tools=list_processes,disk_usage;exp=1735689600");
+        assert_eq!(entry.display_name, "dashboard");
+        assert_eq!(entry.not_after, Some(1735689600));
+        assert!(entry.allowed_tools.contains("list_processes"));
+        assert!(entry.allowed_tools.contains("disk_usage"));
+
+        let unscoped = parse_key_entry("just a plain display name");
+        assert_eq!(unscoped.display_name, "just a plain display name");
+        assert_eq!(unscoped.not_after, None);
+        assert!(unscoped.allowed_tools.is_empty());
+        assert!(!unscoped.is_expired());
+    }
+
+    #[test]
+    fn test_api_key_entry_expiry() {
+        let expired = ApiKeyEntry {
+            display_name: "old".to_string(),
+            not_after: Some(0),
+            allowed_tools: HashSet::new(),
+        };
+        assert!(expired.is_expired());
+    }
+
+    #[test]
+    fn test_parse_proc_net_addr_v4() {
+        // 0100007F = 127.0.0.1 little-endian, 1F90 = 8080
+        assert_eq!(
+            parse_proc_net_addr("0100007F:1F90"),
+            Some("127.0.0.1:8080".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_proc_net_addr_v6() {
+        // all-zero address ("::") listening on port 8080 (1F90)
+        assert_eq!(
+            parse_proc_net_addr("00000000000000000000000000000000:1F90"),
+            Some("[::]:8080".to_string())
+        );
+    }
+
     #[tokio::test]
     async fn test_sysutils_manual_rust() {
         let sysutils = SysUtils::new();
         let report = sysutils
-            .sysutils_manual_rust(Parameters(IapSystemInfoRequest {}))
+            .sysutils_manual_rust(Parameters(IapSystemInfoRequest {
+                format: OutputFormat::Text,
+            }))
             .await;
         assert!(report.contains("System Information Report"));
         assert!(report.contains("CPU Information"));
@@ -470,11 +1941,26 @@ mod tests {
         assert!(!report.contains("Disk Information"));
     }
 
+    #[tokio::test]
+    async fn test_sysutils_manual_rust_json() {
+        let sysutils = SysUtils::new();
+        let report = sysutils
+            .sysutils_manual_rust(Parameters(IapSystemInfoRequest {
+                format: OutputFormat::Json,
+            }))
+            .await;
+        let parsed: serde_json::Value = serde_json::from_str(&report).unwrap();
+        assert!(parsed["cpu"]["cores"].as_u64().unwrap() > 0);
+        assert!(parsed["request_headers"].is_array());
+    }
+
     #[tokio::test]
     async fn test_disk_usage() {
         let sysutils = SysUtils::new();
         let report = sysutils
-            .disk_usage(Parameters(DiskUsageRequest {}))
+            .disk_usage(Parameters(DiskUsageRequest {
+                format: OutputFormat::Text,
+            }))
             .await;
         assert!(report.contains("Disk Usage Report"));
     }
@@ -483,9 +1969,139 @@ mod tests {
     async fn test_list_processes() {
         let sysutils = SysUtils::new();
         let report = sysutils
-            .list_processes(Parameters(ProcessListRequest {}))
+            .list_processes(Parameters(ProcessListRequest {
+                format: OutputFormat::Text,
+            }))
             .await;
         assert!(report.contains("Process List Report"));
         assert!(report.contains("PID"));
     }
+
+    #[tokio::test]
+    async fn test_network_connections() {
+        let sysutils = SysUtils::new();
+        let report = sysutils
+            .network_connections(Parameters(NetworkConnectionsRequest {}))
+            .await;
+        assert!(report.contains("Network Connections Report"));
+        assert!(report.contains("Proto"));
+    }
+
+    #[tokio::test]
+    async fn test_system_monitor() {
+        let sysutils = SysUtils::new();
+        let report = sysutils
+            .system_monitor(Parameters(SystemMonitorRequest {
+                top_n: Some(5),
+                sort_by: Some("memory".to_string()),
+            }))
+            .await;
+        assert!(report.contains("System Monitor Report"));
+        assert!(report.contains("Overall CPU Load"));
+        assert!(report.contains("Per-Core Usage"));
+        assert!(report.contains("Core 0"));
+        assert!(report.contains("Load Average"));
+        assert!(report.contains("Top 5 Processes by memory"));
+    }
+
+    #[tokio::test]
+    async fn test_signal_process_refuses_pid_1() {
+        let sysutils = SysUtils::new();
+        let report = sysutils
+            .signal_process(Parameters(SignalProcessRequest { pid: 1, signal: Some(15) }))
+            .await;
+        assert!(report.contains("refusing to signal PID 1"));
+    }
+
+    #[tokio::test]
+    async fn test_signal_process_refuses_own_pid() {
+        let sysutils = SysUtils::new();
+        let report = sysutils
+            .signal_process(Parameters(SignalProcessRequest {
+                pid: std::process::id(),
+                signal: Some(15),
+            }))
+            .await;
+        assert!(report.contains("refusing to signal the server's own process"));
+    }
+
+    #[tokio::test]
+    async fn test_signal_process_rejects_disallowed_signal() {
+        let sysutils = SysUtils::new();
+        let report = sysutils
+            .signal_process(Parameters(SignalProcessRequest { pid: 999999, signal: Some(6) }))
+            .await;
+        assert!(report.contains("not in the allowlist"));
+    }
+
+    #[tokio::test]
+    async fn test_spawn_process_and_status() {
+        let sysutils = SysUtils::new();
+        let report = sysutils
+            .spawn_process(Parameters(SpawnProcessRequest {
+                command: "echo".to_string(),
+                args: vec!["hello".to_string()],
+                timeout_secs: Some(5),
+                max_output_bytes: None,
+            }))
+            .await;
+        let parsed: serde_json::Value = serde_json::from_str(&report).unwrap();
+        assert_eq!(parsed["running"], false);
+        assert_eq!(parsed["exit_code"], 0);
+        assert!(parsed["stdout"].as_str().unwrap().contains("hello"));
+
+        let id = parsed["id"].as_str().unwrap().to_string();
+        let status = sysutils
+            .process_status(Parameters(ProcessStatusRequest { id }))
+            .await;
+        let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+        assert_eq!(status["exit_code"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_spawn_process_output_cap() {
+        let sysutils = SysUtils::new();
+        let report = sysutils
+            .spawn_process(Parameters(SpawnProcessRequest {
+                command: "yes".to_string(),
+                args: vec![],
+                timeout_secs: Some(1),
+                max_output_bytes: Some(16),
+            }))
+            .await;
+        let parsed: serde_json::Value = serde_json::from_str(&report).unwrap();
+        assert!(parsed["stdout_truncated"].as_bool().unwrap());
+        assert!(parsed["stdout"].as_str().unwrap().len() <= 16);
+    }
+
+    #[tokio::test]
+    async fn test_process_wait_reaps_finished_child() {
+        let sysutils = SysUtils::new();
+        let spawned = sysutils
+            .spawn_process(Parameters(SpawnProcessRequest {
+                command: "echo".to_string(),
+                args: vec!["done".to_string()],
+                timeout_secs: Some(5),
+                max_output_bytes: None,
+            }))
+            .await;
+        let id = serde_json::from_str::<serde_json::Value>(&spawned).unwrap()["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        let waited = sysutils
+            .process_wait(Parameters(ProcessWaitRequest {
+                id: id.clone(),
+                timeout_secs: Some(5),
+            }))
+            .await;
+        let waited: serde_json::Value = serde_json::from_str(&waited).unwrap();
+        assert_eq!(waited["running"], false);
+
+        let status = sysutils
+            .process_status(Parameters(ProcessStatusRequest { id }))
+            .await;
+        assert!(status.contains("Error: no spawned process with id"));
+    }
 }
@@ -20,6 +20,9 @@ struct SystemInfoRequest {}
 #[derive(Debug, serde::Deserialize, JsonSchema)]
 struct DiskUsageRequest {}
 
+#[derive(Debug, serde::Deserialize, JsonSchema)]
+struct CpuLoadRequest {}
+
 fn generate_schema<T: JsonSchema>() -> Arc<serde_json::Map<String, serde_json::Value>> {
     let settings = schemars::generate::SchemaSettings::draft07();
     let generator = settings.into_generator();
@@ -40,6 +43,13 @@ static SYSTEM_INFO_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Valu
 static DISK_USAGE_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
     LazyLock::new(generate_schema::<DiskUsageRequest>);
 
+static CPU_LOAD_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
+    LazyLock::new(generate_schema::<CpuLoadRequest>);
+
+/// Minimum interval `sysinfo` needs between CPU refreshes before `cpu_usage()`
+/// reflects real load rather than 0%.
+const CPU_SAMPLE_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+
 #[derive(Clone)]
 struct SysUtils {
     tool_router: ToolRouter<Self>,
@@ -153,6 +163,29 @@ fn collect_disk_usage() -> String {
     report
 }
 
+async fn collect_cpu_load() -> String {
+    let mut sys = System::new_all();
+    sys.refresh_cpu_all();
+    tokio::time::sleep(CPU_SAMPLE_INTERVAL).await;
+    sys.refresh_cpu_all();
+
+    let mut report = String::new();
+    let _ = writeln!(report, "CPU Load Report");
+    let _ = writeln!(report, "===============\n");
+
+    let global_usage: f32 =
+        sys.cpus().iter().map(|cpu| cpu.cpu_usage()).sum::<f32>() / sys.cpus().len() as f32;
+    let _ = writeln!(report, "Average Usage:    {:.1}%\n", global_usage);
+
+    let _ = writeln!(report, "Per-Core Usage");
+    let _ = writeln!(report, "--------------");
+    for (i, cpu) in sys.cpus().iter().enumerate() {
+        let _ = writeln!(report, "Core {:<3}:         {:.1}%", i, cpu.cpu_usage());
+    }
+
+    report
+}
+
 #[tool_router]
 impl SysUtils {
     fn new() -> Self {
@@ -176,6 +209,14 @@ impl SysUtils {
     async fn disk_usage(&self, _params: Parameters<DiskUsageRequest>) -> String {
         collect_disk_usage()
     }
+
+    #[tool(
+        description = "Sample per-core and average CPU utilization over a short window (~200ms).",
+        input_schema = "CPU_LOAD_SCHEMA.clone()"
+    )]
+    async fn cpu_load(&self, _params: Parameters<CpuLoadRequest>) -> String {
+        collect_cpu_load().await
+    }
 }
 
 #[tool_handler]
@@ -202,6 +243,9 @@ async fn main() -> Result<()> {
         } else if args[1] == "disk" {
             println!("{}", collect_disk_usage());
             return Ok(());
+        } else if args[1] == "cpu" {
+            println!("{}", collect_cpu_load().await);
+            return Ok(());
         }
     }
 
@@ -269,4 +313,13 @@ mod tests {
             .await;
         assert!(report.contains("Disk Usage Report"));
     }
+
+    #[tokio::test]
+    async fn test_cpu_load() {
+        let sysutils = SysUtils::new();
+        let report = sysutils.cpu_load(Parameters(CpuLoadRequest {})).await;
+        assert!(report.contains("CPU Load Report"));
+        assert!(report.contains("Average Usage"));
+        assert!(report.contains("Core 0"));
+    }
 }
@@ -1,4 +1,8 @@
 use anyhow::{Context, Result};
+use async_trait::async_trait;
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use hmac::{Hmac, Mac};
+use rand_core::{OsRng, RngCore};
 use rmcp::{
     handler::server::{ServerHandler, tool::ToolRouter, wrapper::Parameters},
     model::{ServerCapabilities, ServerInfo},
@@ -6,12 +10,19 @@ use rmcp::{
     tool, tool_handler, tool_router,
     transport, ServiceExt,
 };
+use sha2::Sha256;
 use sysinfo::{Disks, Networks, System};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use std::{
+    collections::HashMap,
     fmt::Write,
-    sync::{Arc, LazyLock},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, LazyLock,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 // Google Cloud Dependencies
@@ -19,11 +30,105 @@ use google_apikeys2::ApiKeysService;
 use yup_oauth2::authenticator::ApplicationDefaultCredentialsTypes;
 use yup_oauth2::ApplicationDefaultCredentialsAuthenticator;
 
+/// Output mode shared by the reporting tools: `text` keeps the existing
+/// human-readable report, `json` emits the equivalent result struct.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Debug, serde::Deserialize, JsonSchema)]
+struct SystemInfoRequest {
+    #[serde(default)]
+    format: OutputFormat,
+}
+
 #[derive(Debug, serde::Deserialize, JsonSchema)]
-struct SystemInfoRequest {}
+struct DiskUsageRequest {
+    #[serde(default)]
+    format: OutputFormat,
+}
 
 #[derive(Debug, serde::Deserialize, JsonSchema)]
-struct DiskUsageRequest {}
+struct GcsListObjectsRequest {
+    bucket: String,
+    prefix: Option<String>,
+    delimiter: Option<String>,
+    page_token: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, JsonSchema)]
+struct GcsReadObjectRequest {
+    bucket: String,
+    object: String,
+}
+
+#[derive(Debug, serde::Deserialize, JsonSchema)]
+struct GcsStatObjectRequest {
+    bucket: String,
+    object: String,
+}
+
+/// Outcome of checking a provided key against the cloud-resolved one, in
+/// `json`-format reports. Mirrors the states the `info` CLI prints as text.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum AuthMatchState {
+    /// The caller already authenticated to reach this tool (no key is
+    /// re-checked); used for in-process tool calls like `local_system_info`.
+    Verified,
+    Matched,
+    Mismatch,
+    NotFound,
+    Error,
+    HmacChallenge,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct AuthStatus {
+    match_state: AuthMatchState,
+    detail: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct NetworkInterfaceReport {
+    name: String,
+    rx_bytes: u64,
+    tx_bytes: u64,
+    mac_address: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SystemReport {
+    system_name: String,
+    kernel_version: String,
+    os_version: String,
+    host_name: String,
+    cpu_cores: usize,
+    total_memory_mb: u64,
+    used_memory_mb: u64,
+    total_swap_mb: u64,
+    used_swap_mb: u64,
+    network_interfaces: Vec<NetworkInterfaceReport>,
+    auth: Option<AuthStatus>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DiskEntry {
+    mount_point: String,
+    file_system: String,
+    used_mb: u64,
+    total_mb: u64,
+    usage_percent: f64,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DiskReport {
+    disks: Vec<DiskEntry>,
+}
 
 fn generate_schema<T: JsonSchema>() -> Arc<serde_json::Map<String, serde_json::Value>> {
     let settings = schemars::generate::SchemaSettings::draft07();
@@ -42,30 +147,158 @@ fn generate_schema<T: JsonSchema>() -> Arc<serde_json::Map<String, serde_json::V
 static SYSTEM_INFO_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> = 
     LazyLock::new(generate_schema::<SystemInfoRequest>);
 
-static DISK_USAGE_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> = 
+static DISK_USAGE_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
     LazyLock::new(generate_schema::<DiskUsageRequest>);
 
+static GCS_LIST_OBJECTS_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
+    LazyLock::new(generate_schema::<GcsListObjectsRequest>);
+
+static GCS_READ_OBJECT_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
+    LazyLock::new(generate_schema::<GcsReadObjectRequest>);
+
+static GCS_STAT_OBJECT_SCHEMA: LazyLock<Arc<serde_json::Map<String, serde_json::Value>>> =
+    LazyLock::new(generate_schema::<GcsStatObjectRequest>);
+
 #[derive(Clone)]
 struct SysUtils {
     tool_router: ToolRouter<Self>,
 }
 
+/// A source the MCP API key can be resolved from. Implementations range from
+/// calling the Google API Keys service to reading a plain environment
+/// variable, so the server isn't welded to any one secret store.
+#[async_trait]
+trait SecretProvider: Send + Sync {
+    /// Short identifier used in config (`MCP_SECRET_BACKEND`) and logs.
+    fn name(&self) -> &'static str;
+
+    /// Resolves `secret_name` (e.g. `"MCP API Key"`) to its current value.
+    async fn fetch(&self, secret_name: &str) -> Result<String>;
+}
+
+/// Calls the Google Cloud API Keys service directly via ADC, so it works in
+/// Cloud Run/GCE with attached service accounts and needs no local tooling.
+struct GoogleApiKeysProvider {
+    project_id: String,
+}
+
+#[async_trait]
+impl SecretProvider for GoogleApiKeysProvider {
+    fn name(&self) -> &'static str {
+        "google"
+    }
+
+    async fn fetch(&self, _secret_name: &str) -> Result<String> {
+        fetch_mcp_api_key_library(&self.project_id).await
+    }
+}
+
+/// Shells out to the `gcloud` CLI, which is more forgiving of local User ADC
+/// setups than the generated API client.
+struct GcloudCliProvider {
+    project_id: String,
+}
+
+#[async_trait]
+impl SecretProvider for GcloudCliProvider {
+    fn name(&self) -> &'static str {
+        "gcloud"
+    }
+
+    async fn fetch(&self, _secret_name: &str) -> Result<String> {
+        fetch_mcp_api_key_gcloud(&self.project_id).await
+    }
+}
+
+/// Reads the key straight out of an environment variable, for laptops and CI
+/// that have no GCP credentials at all.
+struct EnvProvider;
+
+#[async_trait]
+impl SecretProvider for EnvProvider {
+    fn name(&self) -> &'static str {
+        "env"
+    }
+
+    async fn fetch(&self, _secret_name: &str) -> Result<String> {
+        std::env::var("MCP_API_KEY_SECRET")
+            .context("MCP_API_KEY_SECRET is not set")
+    }
+}
+
+/// Reads the key from a mounted secret file, for non-GCP deployments (e.g.
+/// Kubernetes secrets mounted as files, Docker secrets).
+struct FileProvider;
+
+#[async_trait]
+impl SecretProvider for FileProvider {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    async fn fetch(&self, _secret_name: &str) -> Result<String> {
+        let path = std::env::var("MCP_API_KEY_SECRET_FILE")
+            .context("MCP_API_KEY_SECRET_FILE is not set")?;
+        let contents = tokio::fs::read_to_string(&path)
+            .await
+            .with_context(|| format!("Failed to read secret file {}", path))?;
+        Ok(contents.trim().to_string())
+    }
+}
+
+/// Builds the chain of `SecretProvider`s to try in order, from
+/// `MCP_SECRET_BACKEND` (a comma-separated list of `google`, `gcloud`, `env`,
+/// `file`). Defaults to `gcloud,google` to match prior behaviour: gcloud
+/// first for local development, falling back to the library for Cloud
+/// Run/GCE.
+fn build_secret_chain(project_id: &str) -> Vec<Box<dyn SecretProvider>> {
+    let backends = std::env::var("MCP_SECRET_BACKEND")
+        .unwrap_or_else(|_| "gcloud,google".to_string());
+
+    backends
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .filter_map(|backend| -> Option<Box<dyn SecretProvider>> {
+            match backend {
+                "google" => Some(Box::new(GoogleApiKeysProvider {
+                    project_id: project_id.to_string(),
+                })),
+                "gcloud" => Some(Box::new(GcloudCliProvider {
+                    project_id: project_id.to_string(),
+                })),
+                "env" => Some(Box::new(EnvProvider)),
+                "file" => Some(Box::new(FileProvider)),
+                other => {
+                    tracing::warn!("Ignoring unknown MCP_SECRET_BACKEND entry: {}", other);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Tries each configured `SecretProvider` in order, returning the first
+/// successful fetch and logging the rest as debug-level misses.
 async fn fetch_mcp_api_key(project_id: &str) -> Result<String> {
     tracing::info!("Fetching MCP API Key for project: {}", project_id);
 
-    // Try gcloud first for local development, it's more reliable with User ADC
-    match fetch_mcp_api_key_gcloud(project_id).await {
-        Ok(key) => {
-            tracing::info!("Successfully fetched API key via gcloud");
-            return Ok(key);
-        }
-        Err(e) => {
-            tracing::debug!("gcloud fetch failed (expected if gcloud not installed): {}", e);
+    let chain = build_secret_chain(project_id);
+    let mut last_err = None;
+    for provider in &chain {
+        match provider.fetch("MCP API Key").await {
+            Ok(key) => {
+                tracing::info!("Successfully fetched API key via {}", provider.name());
+                return Ok(key);
+            }
+            Err(e) => {
+                tracing::debug!("{} fetch failed: {}", provider.name(), e);
+                last_err = Some(e);
+            }
         }
     }
 
-    // Fallback to library-based approach (works in Cloud Run/GCE with Service Accounts)
-    fetch_mcp_api_key_library(project_id).await
+    Err(last_err.unwrap_or_else(|| anyhow::anyhow!("MCP_SECRET_BACKEND resolved to no providers")))
 }
 
 async fn fetch_mcp_api_key_gcloud(project_id: &str) -> Result<String> {
@@ -117,6 +350,44 @@ async fn fetch_mcp_api_key_gcloud(project_id: &str) -> Result<String> {
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
 
+/// Builds an Application Default Credentials authenticator, picking whichever
+/// flow (instance metadata vs. service account key) ADC resolves to. Shared
+/// by every ADC-authenticated caller in this binary (API Keys, GCS).
+async fn build_adc_authenticator() -> Result<
+    yup_oauth2::authenticator::Authenticator<
+        hyper_rustls::HttpsConnector<hyper_util::client::legacy::connect::HttpConnector>,
+    >,
+> {
+    let opts = yup_oauth2::ApplicationDefaultCredentialsFlowOpts::default();
+    let auth_builder = ApplicationDefaultCredentialsAuthenticator::builder(opts).await;
+
+    match auth_builder {
+        ApplicationDefaultCredentialsTypes::InstanceMetadata(builder) => builder
+            .build()
+            .await
+            .context("Failed to build InstanceMetadata authenticator"),
+        ApplicationDefaultCredentialsTypes::ServiceAccount(builder) => builder
+            .build()
+            .await
+            .context("Failed to build ServiceAccount authenticator"),
+    }
+}
+
+/// Fetches a bearer token scoped for the given OAuth scopes via ADC, for use
+/// with plain HTTP clients (e.g. `reqwest`) that don't speak the generated
+/// API-client traits.
+async fn adc_bearer_token(scopes: &[&str]) -> Result<String> {
+    let auth = build_adc_authenticator().await?;
+    let token = auth
+        .token(scopes)
+        .await
+        .context("failed to obtain ADC access token")?;
+    token
+        .token()
+        .map(|t| t.to_string())
+        .context("ADC token response had no token string")
+}
+
 async fn fetch_mcp_api_key_library(project_id: &str) -> Result<String> {
     // 1. Create the API Client first (so we can use it for auth)
     let client = hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
@@ -130,19 +401,7 @@ async fn fetch_mcp_api_key_library(project_id: &str) -> Result<String> {
         );
 
     // 2. Authenticate using Application Default Credentials
-    let opts = yup_oauth2::ApplicationDefaultCredentialsFlowOpts::default();
-    let auth_builder = ApplicationDefaultCredentialsAuthenticator::builder(opts).await;
-
-    let auth: yup_oauth2::authenticator::Authenticator<_> = match auth_builder {
-        ApplicationDefaultCredentialsTypes::InstanceMetadata(builder) => builder
-            .build()
-            .await
-            .context("Failed to build InstanceMetadata authenticator")?,
-        ApplicationDefaultCredentialsTypes::ServiceAccount(builder) => builder
-            .build()
-            .await
-            .context("Failed to build ServiceAccount authenticator")?,
-    };
+    let auth = build_adc_authenticator().await?;
 
     let hub = ApiKeysService::new(client, auth);
 
@@ -182,6 +441,347 @@ async fn fetch_mcp_api_key_library(project_id: &str) -> Result<String> {
     Ok(key_string)
 }
 
+/// Used only when `MCP_PROJECT_ID` is unset, matching the `manual` variant's
+/// project.
+const DEFAULT_PROJECT_ID: &str = "1056842563084";
+
+/// The GCP project to resolve the MCP API key from, configurable via
+/// `MCP_PROJECT_ID` rather than hardcoded so the same binary can serve other
+/// projects.
+fn mcp_project_id() -> String {
+    std::env::var("MCP_PROJECT_ID").unwrap_or_else(|_| DEFAULT_PROJECT_ID.to_string())
+}
+
+const DEFAULT_API_KEY_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Keyed by `project_id` so a single process could in principle resolve keys
+/// for more than one project without cross-contaminating TTLs.
+static API_KEY_CACHE: LazyLock<tokio::sync::RwLock<HashMap<String, (String, Instant)>>> =
+    LazyLock::new(|| tokio::sync::RwLock::new(HashMap::new()));
+static API_KEY_REFRESH_LOCK: LazyLock<tokio::sync::Mutex<()>> =
+    LazyLock::new(|| tokio::sync::Mutex::new(()));
+
+fn api_key_cache_ttl() -> Duration {
+    std::env::var("MCP_API_KEY_CACHE_TTL")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_API_KEY_CACHE_TTL)
+}
+
+/// Returns the cached MCP API key for `project_id`, refreshing it when stale.
+/// Concurrent callers share a single in-flight fetch via `API_KEY_REFRESH_LOCK`,
+/// and a transient refresh failure falls back to serving the last-known-good
+/// key rather than forcing every caller to hit gcloud/the API Keys service at
+/// once. Failures are never cached, so the next call retries.
+async fn cached_mcp_api_key(project_id: &str) -> Result<String> {
+    if let Some((key, fetched_at)) = API_KEY_CACHE.read().await.get(project_id) {
+        if fetched_at.elapsed() < api_key_cache_ttl() {
+            return Ok(key.clone());
+        }
+    }
+
+    let _guard = API_KEY_REFRESH_LOCK.lock().await;
+    // Another task may have refreshed while we waited for the lock.
+    if let Some((key, fetched_at)) = API_KEY_CACHE.read().await.get(project_id) {
+        if fetched_at.elapsed() < api_key_cache_ttl() {
+            return Ok(key.clone());
+        }
+    }
+
+    match fetch_mcp_api_key(project_id).await {
+        Ok(key) => {
+            API_KEY_CACHE
+                .write()
+                .await
+                .insert(project_id.to_string(), (key.clone(), Instant::now()));
+            Ok(key)
+        }
+        Err(e) => {
+            if let Some((key, _)) = API_KEY_CACHE.read().await.get(project_id) {
+                tracing::warn!(
+                    "API key refresh failed, serving last-known-good key: {:?}",
+                    e
+                );
+                return Ok(key.clone());
+            }
+            Err(e)
+        }
+    }
+}
+
+/// Drops the cached entry for `project_id` so the next call refetches,
+/// letting a rotated key take effect without waiting out the TTL or
+/// restarting the process.
+async fn invalidate_cached_api_key(project_id: &str) {
+    API_KEY_CACHE.write().await.remove(project_id);
+}
+
+/// How long a server-issued nonce stays valid and unused before it is
+/// treated as expired.
+const NONCE_TTL: Duration = Duration::from_secs(120);
+
+/// Each `stdiokey` invocation (`nonce`, then the tool call that presents the
+/// HMAC response) is a separate OS process, so an in-memory nonce map can't
+/// be shared between them. Nonces are instead persisted to this file,
+/// keyed by the hex nonce value and the unix timestamp they were issued at;
+/// a nonce is removed as soon as it is checked, whether or not the check
+/// succeeds, so it can never be replayed.
+fn nonce_store_path() -> PathBuf {
+    std::env::var("MCP_NONCE_STORE_PATH")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| std::env::temp_dir().join("stdiokey-nonces.json"))
+}
+
+/// Serializes read-modify-write access to the nonce store file within this
+/// process. Doesn't protect against concurrent `stdiokey` processes racing
+/// on the same file, which is an acceptable risk for this single-operator CLI.
+static NONCE_FILE_LOCK: LazyLock<tokio::sync::Mutex<()>> =
+    LazyLock::new(|| tokio::sync::Mutex::new(()));
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn load_nonce_store(path: &Path) -> HashMap<String, u64> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_nonce_store(path: &Path, store: &HashMap<String, u64>) {
+    if let Ok(json) = serde_json::to_string(store) {
+        let _ = std::fs::write(path, json);
+    }
+}
+
+fn hmac_challenge_enabled() -> bool {
+    std::env::var("MCP_AUTH_MODE")
+        .map(|v| v.eq_ignore_ascii_case("hmac-challenge"))
+        .unwrap_or(false)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Constant-time byte comparison so a mismatching HMAC digest doesn't leak
+/// how many leading bytes matched via early-exit timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Issues a fresh single-use nonce for the HMAC challenge-response flow and
+/// persists it to `path` so it can be checked (and invalidated) exactly once
+/// by whichever later `stdiokey` process presents it.
+async fn issue_nonce_at(path: &Path) -> String {
+    let _guard = NONCE_FILE_LOCK.lock().await;
+
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    let nonce = to_hex(&bytes);
+
+    let mut store = load_nonce_store(path);
+    let ttl_secs = NONCE_TTL.as_secs();
+    store.retain(|_, issued_at| unix_now().saturating_sub(*issued_at) < ttl_secs);
+    store.insert(nonce.clone(), unix_now());
+    save_nonce_store(path, &store);
+
+    nonce
+}
+
+async fn issue_nonce() -> String {
+    issue_nonce_at(&nonce_store_path()).await
+}
+
+/// Consumes `nonce` from the store at `path`, returning whether it was a
+/// live, unexpired, previously issued nonce. Always removes it so it cannot
+/// be replayed either way.
+async fn consume_nonce_at(path: &Path, nonce: &str) -> bool {
+    let _guard = NONCE_FILE_LOCK.lock().await;
+
+    let mut store = load_nonce_store(path);
+    let ttl_secs = NONCE_TTL.as_secs();
+    let result = match store.remove(nonce) {
+        Some(issued_at) => unix_now().saturating_sub(issued_at) < ttl_secs,
+        None => false,
+    };
+    save_nonce_store(path, &store);
+    result
+}
+
+async fn consume_nonce(nonce: &str) -> bool {
+    consume_nonce_at(&nonce_store_path(), nonce).await
+}
+
+/// Computes `HMAC-SHA256(key, nonce)` as a lowercase hex string, the digest
+/// a challenge-response client must return instead of the raw key.
+fn hmac_hex(key: &str, nonce: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(key.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(nonce.as_bytes());
+    to_hex(&mac.finalize().into_bytes())
+}
+
+/// Verifies that `response_hex` is `HMAC-SHA256(expected_key, nonce)`,
+/// comparing the decoded digests in constant time.
+fn verify_hmac_response(expected_key: &str, nonce: &str, response_hex: &str) -> bool {
+    let expected = hmac_hex(expected_key, nonce);
+    match (from_hex(&expected), from_hex(response_hex)) {
+        (Some(a), Some(b)) => constant_time_eq(&a, &b),
+        _ => false,
+    }
+}
+
+const GCS_READ_ONLY_SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read_only";
+
+/// Percent-encodes a GCS bucket/object path component per the JSON API's
+/// expectations (object names may contain `/`, which must also be escaped).
+fn percent_encode(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char);
+            }
+            _ => {
+                out.push('%');
+                out.push_str(&format!("{:02X}", byte));
+            }
+        }
+    }
+    out
+}
+
+async fn gcs_list_objects_impl(req: &GcsListObjectsRequest) -> Result<String> {
+    let token = adc_bearer_token(&[GCS_READ_ONLY_SCOPE]).await?;
+
+    let mut url = format!(
+        "https://storage.googleapis.com/storage/v1/b/{}/o",
+        percent_encode(&req.bucket)
+    );
+    let mut query = Vec::new();
+    if let Some(prefix) = &req.prefix {
+        query.push(format!("prefix={}", percent_encode(prefix)));
+    }
+    if let Some(delimiter) = &req.delimiter {
+        query.push(format!("delimiter={}", percent_encode(delimiter)));
+    }
+    if let Some(page_token) = &req.page_token {
+        query.push(format!("pageToken={}", percent_encode(page_token)));
+    }
+    if !query.is_empty() {
+        url.push('?');
+        url.push_str(&query.join("&"));
+    }
+
+    let response = reqwest::Client::new()
+        .get(&url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("GCS list request failed")?;
+    if !response.status().is_success() {
+        return Err(anyhow::anyhow!("GCS list failed with status {}", response.status()));
+    }
+    let body: serde_json::Value = response.json().await.context("failed to parse GCS list response")?;
+
+    let mut report = String::new();
+    let _ = writeln!(report, "GCS Objects Report");
+    let _ = writeln!(report, "===================\n");
+    let _ = writeln!(report, "Bucket:  {}", req.bucket);
+    if let Some(prefix) = &req.prefix {
+        let _ = writeln!(report, "Prefix:  {}", prefix);
+    }
+    report.push('\n');
+    let _ = writeln!(report, "{:<50} {:>12} {:<30}", "Name", "Size", "Updated");
+    if let Some(items) = body.get("items").and_then(serde_json::Value::as_array) {
+        for item in items {
+            let name = item.get("name").and_then(serde_json::Value::as_str).unwrap_or("<unknown>");
+            let size = item.get("size").and_then(serde_json::Value::as_str).unwrap_or("0");
+            let updated = item.get("updated").and_then(serde_json::Value::as_str).unwrap_or("<unknown>");
+            let _ = writeln!(report, "{:<50} {:>12} {:<30}", name, size, updated);
+        }
+    }
+    if let Some(next) = body.get("nextPageToken").and_then(serde_json::Value::as_str) {
+        let _ = writeln!(report, "\nnextPageToken: {}", next);
+    }
+
+    Ok(report)
+}
+
+async fn gcs_read_object_impl(req: &GcsReadObjectRequest) -> Result<String> {
+    let token = adc_bearer_token(&[GCS_READ_ONLY_SCOPE]).await?;
+
+    let url = format!(
+        "https://storage.googleapis.com/storage/v1/b/{}/o/{}?alt=media",
+        percent_encode(&req.bucket),
+        percent_encode(&req.object)
+    );
+    let response = reqwest::Client::new()
+        .get(&url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("GCS read request failed")?;
+    if !response.status().is_success() {
+        return Err(anyhow::anyhow!("GCS read failed with status {}", response.status()));
+    }
+    let bytes = response.bytes().await.context("failed to read GCS object body")?;
+
+    match std::str::from_utf8(&bytes) {
+        Ok(text) => Ok(text.to_string()),
+        Err(_) => Ok(format!("base64:{}", STANDARD.encode(&bytes))),
+    }
+}
+
+async fn gcs_stat_object_impl(req: &GcsStatObjectRequest) -> Result<String> {
+    let token = adc_bearer_token(&[GCS_READ_ONLY_SCOPE]).await?;
+
+    let url = format!(
+        "https://storage.googleapis.com/storage/v1/b/{}/o/{}",
+        percent_encode(&req.bucket),
+        percent_encode(&req.object)
+    );
+    let response = reqwest::Client::new()
+        .get(&url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("GCS stat request failed")?;
+    if !response.status().is_success() {
+        return Err(anyhow::anyhow!("GCS stat failed with status {}", response.status()));
+    }
+    let meta: serde_json::Value = response.json().await.context("failed to parse GCS object metadata")?;
+
+    let mut report = String::new();
+    let _ = writeln!(report, "GCS Object Metadata");
+    let _ = writeln!(report, "===================\n");
+    let _ = writeln!(report, "Name:          {}", meta.get("name").and_then(serde_json::Value::as_str).unwrap_or("<unknown>"));
+    let _ = writeln!(report, "Size:          {} bytes", meta.get("size").and_then(serde_json::Value::as_str).unwrap_or("0"));
+    let _ = writeln!(report, "Content-Type:  {}", meta.get("contentType").and_then(serde_json::Value::as_str).unwrap_or("<unknown>"));
+    let _ = writeln!(report, "Updated:       {}", meta.get("updated").and_then(serde_json::Value::as_str).unwrap_or("<unknown>"));
+
+    Ok(report)
+}
+
 fn collect_system_info(api_status: Option<&str>) -> String {
     let mut sys = System::new_all();
     sys.refresh_all();
@@ -263,6 +863,37 @@ fn collect_system_info(api_status: Option<&str>) -> String {
     report
 }
 
+/// Structured equivalent of `collect_system_info`, for JSON-format reports.
+fn gather_system_report(auth: Option<AuthStatus>) -> SystemReport {
+    let mut sys = System::new_all();
+    sys.refresh_all();
+
+    let networks = Networks::new_with_refreshed_list();
+    let network_interfaces = networks
+        .iter()
+        .map(|(name, network)| NetworkInterfaceReport {
+            name: name.clone(),
+            rx_bytes: network.total_received(),
+            tx_bytes: network.total_transmitted(),
+            mac_address: network.mac_address().to_string(),
+        })
+        .collect();
+
+    SystemReport {
+        system_name: System::name().unwrap_or_else(|| "<unknown>".to_string()),
+        kernel_version: System::kernel_version().unwrap_or_else(|| "<unknown>".to_string()),
+        os_version: System::os_version().unwrap_or_else(|| "<unknown>".to_string()),
+        host_name: System::host_name().unwrap_or_else(|| "<unknown>".to_string()),
+        cpu_cores: sys.cpus().len(),
+        total_memory_mb: sys.total_memory() / 1024 / 1024,
+        used_memory_mb: sys.used_memory() / 1024 / 1024,
+        total_swap_mb: sys.total_swap() / 1024 / 1024,
+        used_swap_mb: sys.used_swap() / 1024 / 1024,
+        network_interfaces,
+        auth,
+    }
+}
+
 fn collect_disk_usage() -> String {
     let disks = Disks::new_with_refreshed_list();
 
@@ -294,6 +925,35 @@ fn collect_disk_usage() -> String {
     report
 }
 
+/// Structured equivalent of `collect_disk_usage`, for JSON-format reports.
+fn gather_disk_report() -> DiskReport {
+    let disks = Disks::new_with_refreshed_list();
+
+    let entries = disks
+        .iter()
+        .map(|disk| {
+            let total = disk.total_space();
+            let available = disk.available_space();
+            let used = total - available;
+            let usage_percent = if total > 0 {
+                (used as f64 / total as f64) * 100.0
+            } else {
+                0.0
+            };
+
+            DiskEntry {
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                file_system: disk.file_system().to_string_lossy().to_string(),
+                used_mb: used / 1024 / 1024,
+                total_mb: total / 1024 / 1024,
+                usage_percent,
+            }
+        })
+        .collect();
+
+    DiskReport { disks: entries }
+}
+
 #[tool_router]
 impl SysUtils {
     fn new() -> Self {
@@ -303,19 +963,71 @@ impl SysUtils {
     }
 
     #[tool(
-        description = "Get a detailed system information report including kernel, cores, and memory usage.",
+        description = "Get a detailed system information report including kernel, cores, and memory usage. Set format: \"json\" for a machine-readable result.",
         input_schema = "SYSTEM_INFO_SCHEMA.clone()"
     )]
-    async fn local_system_info(&self, _params: Parameters<SystemInfoRequest>) -> String {
-        collect_system_info(Some("Authentication:   [VERIFIED] (Running as MCP Server)\n"))
+    async fn local_system_info(&self, Parameters(req): Parameters<SystemInfoRequest>) -> String {
+        match req.format {
+            OutputFormat::Text => {
+                collect_system_info(Some("Authentication:   [VERIFIED] (Running as MCP Server)\n"))
+            }
+            OutputFormat::Json => {
+                let report = gather_system_report(Some(AuthStatus {
+                    match_state: AuthMatchState::Verified,
+                    detail: Some("Running as MCP Server".to_string()),
+                }));
+                serde_json::to_string_pretty(&report)
+                    .unwrap_or_else(|e| format!("Error serializing system report: {:?}", e))
+            }
+        }
     }
 
     #[tool(
-        description = "Get disk usage information for all mounted disks.",
+        description = "Get disk usage information for all mounted disks. Set format: \"json\" for a machine-readable result.",
         input_schema = "DISK_USAGE_SCHEMA.clone()"
     )]
-    async fn disk_usage(&self, _params: Parameters<DiskUsageRequest>) -> String {
-        collect_disk_usage()
+    async fn disk_usage(&self, Parameters(req): Parameters<DiskUsageRequest>) -> String {
+        match req.format {
+            OutputFormat::Text => collect_disk_usage(),
+            OutputFormat::Json => {
+                let report = gather_disk_report();
+                serde_json::to_string_pretty(&report)
+                    .unwrap_or_else(|e| format!("Error serializing disk report: {:?}", e))
+            }
+        }
+    }
+
+    #[tool(
+        description = "List objects in a GCS bucket, with optional prefix/delimiter filtering and pageToken-based pagination.",
+        input_schema = "GCS_LIST_OBJECTS_SCHEMA.clone()"
+    )]
+    async fn gcs_list_objects(&self, Parameters(req): Parameters<GcsListObjectsRequest>) -> String {
+        match gcs_list_objects_impl(&req).await {
+            Ok(report) => report,
+            Err(e) => format!("Error listing GCS objects: {:?}", e),
+        }
+    }
+
+    #[tool(
+        description = "Read a GCS object's contents, returned as text when valid UTF-8 or base64-encoded otherwise.",
+        input_schema = "GCS_READ_OBJECT_SCHEMA.clone()"
+    )]
+    async fn gcs_read_object(&self, Parameters(req): Parameters<GcsReadObjectRequest>) -> String {
+        match gcs_read_object_impl(&req).await {
+            Ok(contents) => contents,
+            Err(e) => format!("Error reading GCS object: {:?}", e),
+        }
+    }
+
+    #[tool(
+        description = "Get metadata (size, content type, updated time) for a GCS object.",
+        input_schema = "GCS_STAT_OBJECT_SCHEMA.clone()"
+    )]
+    async fn gcs_stat_object(&self, Parameters(req): Parameters<GcsStatObjectRequest>) -> String {
+        match gcs_stat_object_impl(&req).await {
+            Ok(report) => report,
+            Err(e) => format!("Error statting GCS object: {:?}", e),
+        }
     }
 }
 
@@ -359,81 +1071,208 @@ async fn main() {
     }
 }
 
-async fn check_api_key_status(args: &[String]) -> String {
-    let mut status = String::new();
-    let _ = writeln!(status, "MCP API Key Status");
-    let _ = writeln!(status, "------------------");
+/// Finds the value following `--flag` in a CLI argument list.
+fn find_flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    for i in 1..args.len() {
+        if args[i] == flag && i + 1 < args.len() {
+            return Some(&args[i + 1]);
+        }
+    }
+    None
+}
+
+/// Checks the `--key`/`MCP_API_KEY`-provided key against the cloud-resolved
+/// one (or reports the HMAC challenge mode is active), returning a
+/// structured result so callers can render it as text or JSON.
+async fn gather_auth_status(args: &[String]) -> AuthStatus {
+    if hmac_challenge_enabled() {
+        return AuthStatus {
+            match_state: AuthMatchState::HmacChallenge,
+            detail: None,
+        };
+    }
 
     let mut provided_key = std::env::var("MCP_API_KEY").ok();
     if provided_key.is_none() {
-        for i in 1..args.len() {
-            if args[i] == "--key" && i + 1 < args.len() {
-                provided_key = Some(args[i + 1].clone());
-                break;
-            }
+        if let Some(key) = find_flag_value(args, "--key") {
+            provided_key = Some(key.to_string());
         }
     }
 
-    if let Some(key) = provided_key {
-        let _ = writeln!(status, "Provided Key:     [FOUND]");
-        // Fetch cloud key
-        let project_id = "1056842563084";
-        match fetch_mcp_api_key(project_id).await {
-            Ok(expected_key) => {
-                if key == expected_key {
-                    let _ = writeln!(status, "Cloud Match:      [MATCHED]");
-                } else {
-                    let _ = writeln!(status, "Cloud Match:      [MISMATCH]");
-                }
-            }
-            Err(e) => {
-                let _ = writeln!(status, "Cloud Match:      [ERROR: {:?}]", e);
+    let Some(key) = provided_key else {
+        return AuthStatus {
+            match_state: AuthMatchState::NotFound,
+            detail: None,
+        };
+    };
+
+    let project_id = mcp_project_id();
+    match cached_mcp_api_key(&project_id).await {
+        Ok(expected_key) => {
+            let match_state = if key == expected_key {
+                AuthMatchState::Matched
+            } else {
+                AuthMatchState::Mismatch
+            };
+            AuthStatus {
+                match_state,
+                detail: None,
             }
         }
-    } else {
-        let _ = writeln!(status, "Provided Key:     [NOT FOUND]");
+        Err(e) => AuthStatus {
+            match_state: AuthMatchState::Error,
+            detail: Some(format!("{:?}", e)),
+        },
     }
-    status.push('\n');
-    status
 }
 
-async fn handle_main(args: Vec<String>) -> Result<()> {
-    // Check for CLI arguments for direct execution FIRST
-    if args.iter().any(|arg| arg == "info") {
-        let api_status = check_api_key_status(&args).await;
-        println!("{}", collect_system_info(Some(&api_status)));
-        return Ok(());
-    } else if args.iter().any(|arg| arg == "disk") {
-        println!("{}", collect_disk_usage());
-        return Ok(());
+/// Renders an `AuthStatus` as the `info` CLI's original plaintext block.
+fn format_auth_status_text(status: &AuthStatus) -> String {
+    let mut text = String::new();
+    let _ = writeln!(text, "MCP API Key Status");
+    let _ = writeln!(text, "------------------");
+
+    match status.match_state {
+        AuthMatchState::HmacChallenge => {
+            let _ = writeln!(text, "Auth Mode:        [hmac-challenge]");
+        }
+        AuthMatchState::Matched => {
+            let _ = writeln!(text, "Provided Key:     [FOUND]");
+            let _ = writeln!(text, "Cloud Match:      [MATCHED]");
+        }
+        AuthMatchState::Mismatch => {
+            let _ = writeln!(text, "Provided Key:     [FOUND]");
+            let _ = writeln!(text, "Cloud Match:      [MISMATCH]");
+        }
+        AuthMatchState::Error => {
+            let _ = writeln!(text, "Provided Key:     [FOUND]");
+            let _ = writeln!(
+                text,
+                "Cloud Match:      [ERROR: {}]",
+                status.detail.as_deref().unwrap_or("unknown error")
+            );
+        }
+        AuthMatchState::NotFound => {
+            let _ = writeln!(text, "Provided Key:     [NOT FOUND]");
+        }
+        AuthMatchState::Verified => {
+            let _ = writeln!(
+                text,
+                "Authentication:   [VERIFIED]{}",
+                status
+                    .detail
+                    .as_ref()
+                    .map(|d| format!(" ({})", d))
+                    .unwrap_or_default()
+            );
+        }
     }
 
-    // Key Verification Logic (Presence Check)
-    let mut provided_key = std::env::var("MCP_API_KEY").ok();
+    text.push('\n');
+    text
+}
 
-    if provided_key.is_none() {
-        for i in 1..args.len() {
-            if args[i] == "--key" && i + 1 < args.len() {
-                provided_key = Some(args[i + 1].clone());
-                break;
+/// Authenticates the caller either via the plaintext `--key`/`MCP_API_KEY`
+/// path, or, when `MCP_AUTH_MODE=hmac-challenge` is set, by checking a
+/// single-use nonce against an `HMAC-SHA256(expected_key, nonce)` response so
+/// the shared key itself never has to be passed on the command line.
+async fn authenticate(args: &[String], project_id: &str) -> Result<()> {
+    if hmac_challenge_enabled() {
+        let nonce = std::env::var("MCP_NONCE")
+            .ok()
+            .or_else(|| find_flag_value(args, "--nonce").map(str::to_string))
+            .ok_or_else(|| anyhow::anyhow!("Authentication Required: pass the server-issued nonce via --nonce <NONCE> or MCP_NONCE (obtain one with the `nonce` subcommand)"))?;
+        let response = std::env::var("MCP_HMAC_RESPONSE")
+            .ok()
+            .or_else(|| find_flag_value(args, "--hmac-response").map(str::to_string))
+            .ok_or_else(|| anyhow::anyhow!("Authentication Required: pass HMAC-SHA256(expected_key, nonce) as hex via --hmac-response <HEX> or MCP_HMAC_RESPONSE"))?;
+
+        if !consume_nonce(&nonce).await {
+            return Err(anyhow::anyhow!(
+                "Authentication Failed: nonce is unknown, already used, or expired"
+            ));
+        }
+
+        let expected_key = cached_mcp_api_key(project_id)
+            .await
+            .context("Failed to fetch MCP API Key")?;
+
+        if !verify_hmac_response(&expected_key, &nonce, &response) {
+            // A mismatch can mean the key rotated since it was cached, not
+            // just that the caller is wrong. Force a refetch and give it one
+            // more try before failing, so a rotated key takes effect
+            // immediately instead of waiting out the TTL.
+            invalidate_cached_api_key(project_id).await;
+            let refreshed_key = cached_mcp_api_key(project_id)
+                .await
+                .context("Failed to fetch MCP API Key")?;
+            if refreshed_key == expected_key || !verify_hmac_response(&refreshed_key, &nonce, &response) {
+                return Err(anyhow::anyhow!("Authentication Failed: HMAC response did not match"));
             }
         }
+
+        return Ok(());
     }
 
+    // Plaintext fallback, retained for backward compatibility.
+    let mut provided_key = std::env::var("MCP_API_KEY").ok();
     if provided_key.is_none() {
-        return Err(anyhow::anyhow!("Authentication Required: Please provide the API Key using --key <KEY> or MCP_API_KEY environment variable"));
+        if let Some(key) = find_flag_value(args, "--key") {
+            provided_key = Some(key.to_string());
+        }
     }
 
-    // Fetch MCP API Key and Verify
-    // Hardcoded project ID matching the manual variant
-    let project_id = "1056842563084";
-    let expected_key = fetch_mcp_api_key(project_id).await
+    let provided_key = provided_key.ok_or_else(|| anyhow::anyhow!("Authentication Required: Please provide the API Key using --key <KEY> or MCP_API_KEY environment variable"))?;
+
+    let expected_key = cached_mcp_api_key(project_id)
+        .await
         .context("Failed to fetch MCP API Key")?;
 
-    if provided_key.as_ref() != Some(&expected_key) {
-        return Err(anyhow::anyhow!("Authentication Failed: Invalid API Key provided"));
+    if provided_key != expected_key {
+        // Same rationale as the HMAC path above: a mismatch may just mean
+        // the cached key is stale because it rotated, so force one refetch
+        // before declaring authentication failure.
+        invalidate_cached_api_key(project_id).await;
+        let refreshed_key = cached_mcp_api_key(project_id)
+            .await
+            .context("Failed to fetch MCP API Key")?;
+        if provided_key != refreshed_key {
+            return Err(anyhow::anyhow!("Authentication Failed: Invalid API Key provided"));
+        }
     }
 
+    Ok(())
+}
+
+async fn handle_main(args: Vec<String>) -> Result<()> {
+    // Check for CLI arguments for direct execution FIRST
+    if args.iter().any(|arg| arg == "nonce") {
+        println!("{}", issue_nonce().await);
+        return Ok(());
+    } else if args.iter().any(|arg| arg == "info") {
+        let auth = gather_auth_status(&args).await;
+        if find_flag_value(&args, "--format") == Some("json") {
+            let report = gather_system_report(Some(auth));
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            let api_status = format_auth_status_text(&auth);
+            println!("{}", collect_system_info(Some(&api_status)));
+        }
+        return Ok(());
+    } else if args.iter().any(|arg| arg == "disk") {
+        if find_flag_value(&args, "--format") == Some("json") {
+            println!("{}", serde_json::to_string_pretty(&gather_disk_report())?);
+        } else {
+            println!("{}", collect_disk_usage());
+        }
+        return Ok(());
+    }
+
+    // Configurable via MCP_PROJECT_ID; defaults to the project used by the
+    // manual variant.
+    let project_id = mcp_project_id();
+    authenticate(&args, &project_id).await?;
+
     tracing::info!("Authentication Successful");
 
     tracing::info!("Starting stdiokey MCP Stdio server");
@@ -465,7 +1304,9 @@ mod tests {
     async fn test_local_system_info() {
         let sysutils = SysUtils::new();
         let report = sysutils
-            .local_system_info(Parameters(SystemInfoRequest {}))
+            .local_system_info(Parameters(SystemInfoRequest {
+                format: OutputFormat::Text,
+            }))
             .await;
         assert!(report.contains("System Information Report"));
         assert!(report.contains("CPU Information"));
@@ -473,12 +1314,132 @@ mod tests {
         assert!(!report.contains("Disk Information"));
     }
 
+    #[tokio::test]
+    async fn test_local_system_info_json() {
+        let sysutils = SysUtils::new();
+        let report = sysutils
+            .local_system_info(Parameters(SystemInfoRequest {
+                format: OutputFormat::Json,
+            }))
+            .await;
+        let parsed: serde_json::Value = serde_json::from_str(&report).unwrap();
+        assert!(parsed["system_name"].is_string());
+        assert_eq!(parsed["auth"]["match_state"], "verified");
+    }
+
     #[tokio::test]
     async fn test_disk_usage() {
         let sysutils = SysUtils::new();
         let report = sysutils
-            .disk_usage(Parameters(DiskUsageRequest {}))
+            .disk_usage(Parameters(DiskUsageRequest {
+                format: OutputFormat::Text,
+            }))
             .await;
         assert!(report.contains("Disk Usage Report"));
     }
+
+    #[tokio::test]
+    async fn test_disk_usage_json() {
+        let sysutils = SysUtils::new();
+        let report = sysutils
+            .disk_usage(Parameters(DiskUsageRequest {
+                format: OutputFormat::Json,
+            }))
+            .await;
+        let parsed: serde_json::Value = serde_json::from_str(&report).unwrap();
+        assert!(parsed["disks"].is_array());
+    }
+
+    #[tokio::test]
+    async fn test_cached_mcp_api_key_reuses_fresh_entry() {
+        API_KEY_CACHE
+            .write()
+            .await
+            .insert("test-fresh".to_string(), ("fresh-key".to_string(), Instant::now()));
+
+        // A fresh cache entry is served directly, with no fetch attempted.
+        let key = cached_mcp_api_key("test-fresh").await.unwrap();
+        assert_eq!(key, "fresh-key");
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_cached_api_key_forces_refetch() {
+        API_KEY_CACHE.write().await.insert(
+            "test-invalidate".to_string(),
+            ("stale-key".to_string(), Instant::now()),
+        );
+
+        invalidate_cached_api_key("test-invalidate").await;
+
+        assert!(
+            API_KEY_CACHE
+                .read()
+                .await
+                .get("test-invalidate")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("reports/2026/q1.csv"), "reports%2F2026%2Fq1.csv");
+        assert_eq!(percent_encode("simple-name_1.0"), "simple-name_1.0");
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"abcdef", b"abcdef"));
+        assert!(!constant_time_eq(b"abcdef", b"abcxyz"));
+        assert!(!constant_time_eq(b"short", b"longer-slice"));
+    }
+
+    #[test]
+    fn test_hmac_response_roundtrip_and_tamper_detection() {
+        let response = hmac_hex("super-secret-key", "deadbeef");
+        assert!(verify_hmac_response("super-secret-key", "deadbeef", &response));
+        assert!(!verify_hmac_response("super-secret-key", "deadbeef", &hmac_hex("wrong-key", "deadbeef")));
+        assert!(!verify_hmac_response("super-secret-key", "other-nonce", &response));
+    }
+
+    /// Unique per-call nonce store path so parallel `cargo test` threads
+    /// don't race on the same file.
+    fn test_nonce_store_path() -> std::path::PathBuf {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        std::env::temp_dir().join(format!(
+            "stdiokey-nonces-test-{}-{}.json",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_nonce_is_single_use() {
+        let path = test_nonce_store_path();
+        let nonce = issue_nonce_at(&path).await;
+        assert!(consume_nonce_at(&path, &nonce).await);
+        // Replaying the same nonce must fail.
+        assert!(!consume_nonce_at(&path, &nonce).await);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_consume_nonce_rejects_unknown_nonce() {
+        let path = test_nonce_store_path();
+        assert!(!consume_nonce_at(&path, "never-issued").await);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    /// Simulates the real flow: nonce issued in one invocation, consumed by
+    /// a handle that only has the on-disk store path, mirroring how the
+    /// separate `nonce` and tool-call processes share state.
+    #[tokio::test]
+    async fn test_nonce_shared_across_separate_invocations() {
+        let path = test_nonce_store_path();
+        let nonce = issue_nonce_at(&path).await;
+
+        // A "fresh process" only has the nonce string and the well-known
+        // store path, not the issuing process's memory.
+        assert!(consume_nonce_at(&path, &nonce).await);
+        let _ = std::fs::remove_file(&path);
+    }
 }
\ No newline at end of file